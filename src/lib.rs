@@ -5,746 +5,7660 @@
 //
 //=======================================================================//
 
-use std::{
-    fs::File,
-    io::{BufRead, BufReader}
-};
-
 use hill_vacuum_shared::{
     continue_if_no_match,
     match_or_panic,
-    return_if_no_match,
     ManualItem,
     NextValue,
     TEXTURE_HEIGHT_RANGE
 };
-use proc_macro::{Ident, TokenStream, TokenTree};
+use proc_macro::{Delimiter, Group, Ident, TokenStream, TokenTree};
 
 //=======================================================================//
-// FUNCTIONS
+// CONSTANTS
 //
 //=======================================================================//
 
-/// Checks whever `value` is a comma.
-/// # Panics
-/// Function panics if `value` is not a comma.
-#[inline]
-fn is_comma(value: TokenTree)
+/// The maximum amount of keys of the chords generated by [`bind_enum`].
+const MAX_CHORD_LEN: usize = 3;
+
+//=======================================================================//
+// TYPES
+//
+//=======================================================================//
+
+/// The fields of an enum variant.
+enum Fields
 {
-    assert!(match_or_panic!(value, TokenTree::Punct(p), p).as_char() == ',');
+    /// No fields.
+    Unit,
+    /// Unnamed fields, described by their types.
+    Unnamed(Vec<String>),
+    /// Named fields, described by their names and types.
+    Named(Vec<(String, String)>)
 }
 
 //=======================================================================//
 
-/// Executes `f` for each Ident contained in `group`'s stream.
-/// # Panics
-/// Panics if `group` is not a `TokenTree::Group(_)`.
-fn for_each_ident_in_group<F: FnMut(Ident)>(group: TokenTree, mut f: F)
+/// A variant of an enum.
+struct Variant
 {
-    for ident in match_or_panic!(group, TokenTree::Group(g), g)
-        .stream()
-        .into_iter()
-        .filter_map(|item| return_if_no_match!(item, TokenTree::Ident(ident), Some(ident), None))
+    /// The outer attributes.
+    attributes:       Vec<Group>,
+    /// The name.
+    ident:            Ident,
+    /// The fields.
+    fields:           Fields,
+    /// The discriminant, either explicit or inferred from the previous variant.
+    discriminant:     i128,
+    /// The expression evaluating to the discriminant, if it depends on the `#[cfg]` attributes of
+    /// the previous variants.
+    cfg_discriminant: Option<String>
+}
+
+impl Variant
+{
+    /// Whether the variant is annotated with `#[enum_skip]`.
+    #[inline]
+    #[must_use]
+    fn skipped(&self) -> bool { find_attribute(&self.attributes, "enum_skip").is_some() }
+
+    /// Returns the predicate of the `#[cfg]` attributes of the variant, if any.
+    #[must_use]
+    fn cfg(&self) -> Option<String>
     {
-        f(ident);
+        let mut predicates = self
+            .attributes
+            .iter()
+            .filter_map(|attribute| {
+                let mut iter = attribute.stream().into_iter();
+
+                if iter.next()?.to_string() != "cfg"
+                {
+                    return None;
+                }
+
+                Some(
+                    match_or_panic!(iter.next_value(), TokenTree::Group(g), g)
+                        .stream()
+                        .to_string()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        match predicates.len()
+        {
+            0 => None,
+            1 => predicates.pop(),
+            _ => Some(format!("all({})", predicates.join(", ")))
+        }
     }
-}
 
-//=======================================================================//
+    /// Returns the `#[cfg]` attribute to be put on the items generated for the variant, or an
+    /// empty string if the variant is always compiled.
+    #[inline]
+    #[must_use]
+    fn cfg_attribute(&self) -> String
+    {
+        self.cfg()
+            .map_or_else(String::new, |predicate| format!("#[cfg({predicate})] "))
+    }
 
-/// Extracts the name of an enum for `iter`.
-/// # Panics
-/// Panics if `iter` does not belong to an enum.
-#[inline]
-#[must_use]
-fn enum_ident(iter: &mut impl Iterator<Item = TokenTree>) -> Ident
-{
-    for item in iter.by_ref()
+    /// Returns the expression evaluating to the discriminant.
+    #[inline]
+    #[must_use]
+    fn discriminant_expr(&self) -> String
     {
-        let ident = continue_if_no_match!(item, TokenTree::Ident(ident), ident);
+        self.cfg_discriminant
+            .clone()
+            .unwrap_or_else(|| self.discriminant.to_string())
+    }
 
-        if &ident.to_string() == "enum"
+    /// Returns the implicit discriminant of the following variant, given the one `implicit` of
+    /// `self`.
+    #[must_use]
+    fn next_discriminant(&self, implicit: &(i128, Option<String>)) -> (i128, Option<String>)
+    {
+        let value = self.discriminant + 1;
+        let expr = format!("({} + 1)", self.discriminant_expr());
+
+        match self.cfg()
         {
-            return match_or_panic!(iter.next_value(), TokenTree::Ident(i), i);
+            Some(predicate) =>
+            {
+                let fallback = implicit.1.clone().unwrap_or_else(|| implicit.0.to_string());
+                (
+                    value,
+                    Some(format!("(if cfg!({predicate}) {{ {expr} }} else {{ {fallback} }})"))
+                )
+            },
+            None => (value, self.cfg_discriminant.as_ref().map(|_| expr))
         }
     }
 
-    panic!();
-}
+    /// Returns the pattern matching the variant regardless of its fields, prefixed by `path`, such
+    /// as `Self::A(..)`.
+    #[inline]
+    #[must_use]
+    fn pattern(&self, path: &str) -> String
+    {
+        let ident = &self.ident;
 
-//=======================================================================//
+        match self.fields
+        {
+            Fields::Unit => format!("{path}::{ident}"),
+            Fields::Unnamed(_) => format!("{path}::{ident}(..)"),
+            Fields::Named(_) => format!("{path}::{ident} {{ .. }}")
+        }
+    }
 
-/// Implements a constant representing the size of the `input` enum.
+    /// Returns the expression building the variant, prefixed by `path`. The fields, if any, are
+    /// initialized with their default values.
+    /// # Panics
+    /// Panics if the variant has fields and is not annotated with `#[enum_default_payload]`.
+    #[must_use]
+    fn constructor(&self, path: &str) -> String
+    {
+        let ident = &self.ident;
 
-#[proc_macro_derive(EnumSize)]
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
-pub fn enum_size(input: TokenStream) -> TokenStream
-{
-    let mut iter = input.into_iter();
-    format!(
-        "impl {} {{ pub const SIZE: usize = {}; }}",
-        enum_ident(&mut iter),
-        enum_len(iter)
-    )
-    .parse()
-    .unwrap()
+        if !matches!(self.fields, Fields::Unit) &&
+            find_attribute(&self.attributes, "enum_default_payload").is_none()
+        {
+            panic!(
+                "Variant {ident} has fields, annotate it with #[enum_default_payload] to build it \
+                 with their default values."
+            );
+        }
+
+        match &self.fields
+        {
+            Fields::Unit => format!("{path}::{ident}"),
+            Fields::Unnamed(types) =>
+            {
+                format!("{path}::{ident}({})", "Default::default(), ".repeat(types.len()))
+            },
+            Fields::Named(fields) =>
+            {
+                let mut value = format!("{path}::{ident} {{ ");
+
+                for (name, _) in fields
+                {
+                    value.push_str(&format!("{name}: Default::default(), "));
+                }
+
+                value.push('}');
+                value
+            }
+        }
+    }
 }
 
 //=======================================================================//
 
-/// Returns the amount of elements in an enum.
-#[allow(clippy::missing_panics_doc)]
-#[inline]
-#[must_use]
-fn enum_len(mut iter: impl Iterator<Item = TokenTree>) -> usize
+/// The generic parameters and the where clause of an enum.
+#[derive(Default)]
+struct Generics
 {
-    let mut i = 0;
-    for_each_ident_in_group(iter.next_value(), |_| i += 1);
-    i
+    /// The parameters with their bounds, such as `<'a, T: Clone>`.
+    params:       String,
+    /// The names of the parameters, such as `<'a, T>`.
+    args:         String,
+    /// The where clause, such as `where T: Default`.
+    where_clause: String
 }
 
-//=======================================================================//
-
-/// Implements From `usize` for a plain enum.
-/// # Panics
-/// Panics if `input` does not belong to an enum.
-#[proc_macro_derive(EnumFromUsize)]
-#[must_use]
-pub fn enum_from_usize(input: TokenStream) -> TokenStream
+impl Generics
 {
-    let mut iter = input.into_iter();
-    let enum_ident = enum_ident(&mut iter).to_string();
+    /// Parses the generic parameters and the where clause following the name of an enum, returning
+    /// them along with the body of the enum.
+    /// # Panics
+    /// Panics if the enum has no body.
+    #[must_use]
+    fn new(iter: &mut impl Iterator<Item = TokenTree>) -> (Self, TokenTree)
+    {
+        let mut params = Vec::new();
+        let mut where_clause = Vec::new();
+        let mut depth = 0usize;
+        let mut arrow = false;
 
-    let mut from_impl = format!(
-        "impl From<usize> for {enum_ident}
-        {{
-            #[inline]
-            #[must_use] fn from(value: usize) -> Self
-            {{
-                match value
-                {{
-        "
-    );
+        for item in iter.by_ref()
+        {
+            if let TokenTree::Punct(p) = &item
+            {
+                match p.as_char()
+                {
+                    '<' if where_clause.is_empty() =>
+                    {
+                        depth += 1;
 
-    let mut i = 0;
+                        if depth == 1
+                        {
+                            continue;
+                        }
+                    },
+                    // The '>' of '->' does not close an angle bracket.
+                    '>' if depth != 0 && !arrow =>
+                    {
+                        depth -= 1;
 
-    for_each_ident_in_group(iter.next_value(), |ident| {
-        from_impl.push_str(&format!("{i} => {enum_ident}::{ident},\n"));
-        i += 1;
-    });
+                        if depth == 0
+                        {
+                            continue;
+                        }
+                    },
+                    _ => ()
+                };
 
-    from_impl.push_str("_ => unreachable!() } } }");
-    from_impl.parse().unwrap()
-}
+                arrow = p.as_char() == '-';
+            }
+            else
+            {
+                arrow = false;
+            }
 
-//=======================================================================//
+            if depth != 0
+            {
+                params.push(item);
+                continue;
+            }
 
-/// Implements a method that returns an iterator to the values of a plain enum.
-#[proc_macro_derive(EnumIter)]
-#[allow(clippy::missing_panics_doc)]
-#[must_use]
-pub fn enum_iter(input: TokenStream) -> TokenStream
-{
-    let mut iter = input.into_iter();
-    let enum_ident = enum_ident(&mut iter).to_string();
-    let enum_len = enum_len(iter.clone());
-    let mut enum_match = String::new();
+            if matches!(&item, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace)
+            {
+                let mut generics = Self {
+                    where_clause: where_clause.into_iter().collect::<TokenStream>().to_string(),
+                    ..Default::default()
+                };
+                generics.push_params(params.into_iter().collect());
+                return (generics, item);
+            }
 
-    let mut i = 0;
-    for_each_ident_in_group(iter.next_value(), |ident| {
-        enum_match.push_str(&format!("{i} => Some({enum_ident}::{ident}),\n"));
-        i += 1;
-    });
+            where_clause.push(item);
+        }
 
-    enum_match.push_str("_ => None");
+        panic!("Enum has no body.");
+    }
 
-    format!(
-        "
-        impl {enum_ident}
-        {{
-            #[inline]
-            pub fn iter() -> impl ExactSizeIterator<Item = Self>
-            {{
-                struct EnumIterator(usize, usize);
+    /// Stores the generic parameters contained in `stream`.
+    fn push_params(&mut self, stream: TokenStream)
+    {
+        let mut params = Vec::new();
+        let mut args = Vec::new();
 
-                impl ExactSizeIterator for EnumIterator
-                {{
-                    #[inline]
-                    #[must_use]
-                    fn len(&self) -> usize {{ self.1 - self.0 }}
-                }}
+        for segment in split_at_commas(stream)
+        {
+            // Skip the attributes of the parameter.
+            let mut start = 0;
 
-                impl Iterator for EnumIterator
-                {{
-                    type Item = {enum_ident};
+            while matches!(&segment[start], TokenTree::Punct(p) if p.as_char() == '#')
+            {
+                start += 2;
+            }
 
-                    #[inline]
-                    fn next(&mut self) -> Option<Self::Item>
-                    {{
-                        let value = match self.0
-                        {{
-                            {enum_match}
-                        }};
+            let segment = &segment[start..];
+            let name = match &segment[0]
+            {
+                TokenTree::Punct(p) if p.as_char() == '\'' => &segment[..2],
+                TokenTree::Ident(ident) if &ident.to_string() == "const" => &segment[1..2],
+                _ => &segment[..1]
+            };
 
-                        self.0 += 1;
-                        value
-                    }}
-                }}
+            // The default value, if any, is not part of the impl parameters.
+            let mut depth = 0usize;
+            let end = segment
+                .iter()
+                .position(|item| {
+                    match item
+                    {
+                        TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+                        TokenTree::Punct(p) if p.as_char() == '>' =>
+                        {
+                            depth = depth.saturating_sub(1)
+                        },
+                        TokenTree::Punct(p) if p.as_char() == '=' && depth == 0 => return true,
+                        _ => ()
+                    };
+
+                    false
+                })
+                .unwrap_or(segment.len());
+
+            params.push(segment[..end].iter().cloned().collect::<TokenStream>().to_string());
+            args.push(name.iter().cloned().collect::<TokenStream>().to_string());
+        }
 
-                EnumIterator(0, {enum_len})
-            }}
-        }}
-        "
-    )
-    .parse()
-    .unwrap()
+        if !params.is_empty()
+        {
+            self.params = format!("<{}>", params.join(", "));
+            self.args = format!("<{}>", args.join(", "));
+        }
+    }
+
+    /// Whether there are no generic parameters.
+    #[inline]
+    #[must_use]
+    fn is_empty(&self) -> bool { self.params.is_empty() }
 }
 
 //=======================================================================//
 
-/// Generates an array of static [`str`] with name, size, and prefix defined in `stream`.
-/// # Examples
-/// ```
-/// str_array(ARRAY, 4, i_);
-/// // Equivalent to
-/// const ARRAY: [&'static str; 4] = ["i_0", "i_1", "i_2", "i_3"];
-/// ```
-/// # Panics
-/// Panics if `input` is not properly formatted.
-#[proc_macro]
-pub fn str_array(input: TokenStream) -> TokenStream
+/// A plain enum parsed from the input of a derive macro.
+struct Enum
 {
-    let mut iter = input.into_iter();
+    /// The outer attributes.
+    attributes: Vec<Group>,
+    /// The visibility.
+    visibility: String,
+    /// The name.
+    ident:      Ident,
+    /// The generic parameters.
+    generics:   Generics,
+    /// The variants.
+    variants:   Vec<Variant>
+}
+
+impl Enum
+{
+    /// Parses the enum contained in `input`.
+    /// # Panics
+    /// Panics if `input` does not belong to an enum.
+    #[must_use]
+    fn new(input: TokenStream) -> Self
+    {
+        let mut attributes = Vec::new();
+        let mut visibility = String::new();
+        let mut iter = input.into_iter();
 
-    let ident = iter.next_value().to_string();
-    is_comma(iter.next_value());
+        while let Some(item) = iter.next()
+        {
+            match item
+            {
+                TokenTree::Punct(p) if p.as_char() == '#' =>
+                {
+                    attributes.push(match_or_panic!(iter.next_value(), TokenTree::Group(g), g));
+                },
+                TokenTree::Ident(ident) if &ident.to_string() == "enum" =>
+                {
+                    let ident = match_or_panic!(iter.next_value(), TokenTree::Ident(i), i);
+                    let (generics, body) = Generics::new(&mut iter);
+
+                    return Self {
+                        attributes,
+                        visibility,
+                        ident,
+                        generics,
+                        variants: enum_variants(body)
+                    };
+                },
+                item =>
+                {
+                    visibility.push_str(&item.to_string());
+                    visibility.push(' ');
+                }
+            }
+        }
 
-    let amount = iter.next_value().to_string().parse::<u16>().unwrap();
+        panic!("Input is not an enum.");
+    }
 
-    let prefix = if let Some(v) = iter.next()
+    /// Removes the variants annotated with `#[enum_skip]`. The discriminants of the remaining
+    /// variants are unaffected.
+    #[inline]
+    #[must_use]
+    fn without_skipped(mut self) -> Self
     {
-        is_comma(v);
-        let v = iter.next_value();
-        assert!(iter.next().is_none());
-        v.to_string()
+        self.variants.retain(|variant| !variant.skipped());
+        self
     }
-    else
+
+    /// Returns the arguments of the outer attribute named `name`, if any.
+    #[inline]
+    #[must_use]
+    fn attribute(&self, name: &str) -> Option<TokenStream>
     {
-        String::new()
-    };
+        find_attribute(&self.attributes, name)
+    }
+
+    /// Returns the header of an impl block of `trait_path` for the enum, or of an inherent impl
+    /// block if `trait_path` is empty.
+    #[inline]
+    #[must_use]
+    fn impl_header(&self, trait_path: &str) -> String
+    {
+        let Generics {
+            params,
+            args,
+            where_clause
+        } = &self.generics;
+        let ident = &self.ident;
+
+        if trait_path.is_empty()
+        {
+            format!("impl{params} {ident}{args} {where_clause}")
+        }
+        else
+        {
+            format!("impl{params} {trait_path} for {ident}{args} {where_clause}")
+        }
+    }
+
+    /// Returns the visibility of the items generated for the enum, `pub` unless specified by
+    /// `#[enum_vis(...)]`.
+    #[inline]
+    #[must_use]
+    fn item_visibility(&self) -> String
+    {
+        self.attribute("enum_vis")
+            .map_or_else(|| "pub ".to_string(), |stream| format!("{stream} "))
+    }
 
-    let mut result = format!("const {ident}: [&'static str; {amount}] = [");
+    /// Returns the visibility of the types generated for the enum, the same as the enum unless
+    /// specified by `#[enum_vis(...)]`.
+    #[inline]
+    #[must_use]
+    fn type_visibility(&self) -> String
+    {
+        self.attribute("enum_vis")
+            .map_or_else(|| self.visibility.clone(), |stream| format!("{stream} "))
+    }
 
-    for i in 0..amount
+    /// Asserts that the enum has no generic parameters, for the derives generating types indexed
+    /// by the enum.
+    /// # Panics
+    /// Panics if the enum has generic parameters.
+    #[inline]
+    fn assert_not_generic(&self, derive: &str)
     {
-        result.push_str(&format!("\"{prefix}{i}\", "));
+        assert!(self.generics.is_empty(), "{derive} does not support generic enums.");
     }
+}
 
-    result.push_str("];");
-    result.parse().unwrap()
+//=======================================================================//
+
+/// A piece of the text of a manual explanation.
+enum MarkdownSpan<'a>
+{
+    /// Plain text.
+    Text(&'a str),
+    /// A link, described by its text and url.
+    Link(&'a str, &'a str),
+    /// An image, described by its alternative text and path.
+    Image(&'a str, &'a str),
+    /// An ```ini code block.
+    Code(&'a str),
+    /// Bold text.
+    Bold(&'a str),
+    /// Italic text.
+    Italic(&'a str)
 }
 
 //=======================================================================//
 
-/// Generates the built-in manual from some of the markdown files in the `docs` directory.
-#[allow(clippy::missing_panics_doc)]
-#[proc_macro]
-pub fn generate_manual(_: TokenStream) -> TokenStream
+/// The layout of the manual, set through the `column_width`, `wrap`, and `separators` options.
+struct ManualLayout
 {
-    const SHOW_EXPLANATION: &str = "
-    use crate::map::editor::state::{ui::{Tool, SubTool}, core::tool::ToolInterface};
+    /// The width of the column containing the commands and the subtool icons.
+    column_width: f32,
+    /// Whether the entries wrap their content.
+    wrap:         bool,
+    /// Whether the sections are separated.
+    separators:   bool
+}
 
+impl Default for ManualLayout
+{
     #[inline]
-    fn show_explanation<F: FnOnce(&mut egui::Ui)>(ui: &mut egui::Ui, left: F, explanation: &str)
+    fn default() -> Self
     {
-        ui.horizontal_wrapped(|ui| {
-            egui_extras::StripBuilder::new(ui)
-                .size(egui_extras::Size::exact(250f32))
-                .size(egui_extras::Size::remainder())
-                .horizontal(|mut strip| {
-                    strip.cell(|ui| {
-                        left(ui);
-                    });
+        Self {
+            column_width: 250f32,
+            wrap:         true,
+            separators:   true
+        }
+    }
+}
 
-                    strip.cell(|ui| {
-                        ui.label(explanation);
-                    });
-                });
-        });
-    }";
+impl ManualLayout
+{
+    /// Sets the layout option `key` to `value`, returning whether `key` is a layout option.
+    /// # Panics
+    /// Panics if `value` is not a valid value of the option.
+    #[inline]
+    #[must_use]
+    fn set(&mut self, key: &str, value: &str) -> bool
+    {
+        let flag = || {
+            value
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid {key} value {value}, expected a bool."))
+        };
 
-    let path = std::env::current_dir().unwrap();
-    let path = path.to_str().unwrap();
-
-    let body = hill_vacuum_shared::process_docs(
-        |string| {
-            string.push_str("ui.collapsing(\n");
-        },
-        |string, name, item| {
-            match item
+        match key
+        {
+            "column_width" =>
             {
-                ManualItem::Regular =>
-                {
-                    string.push('\"');
-                    string.push_str(&name.to_ascii_uppercase());
-                    string.push_str("\",\n");
-                    string.push_str("|ui| {\nui.vertical(|ui| {\n");
-                },
-                ManualItem::Tool =>
-                {
-                    let mut chars = name.chars();
-                    let mut tool = chars.next_value().to_ascii_uppercase().to_string();
+                self.column_width = value
+                    .parse()
+                    .ok()
+                    .filter(|width: &f32| *width > 0f32)
+                    .unwrap_or_else(|| panic!("Invalid column_width value {value}."));
+            },
+            "wrap" => self.wrap = flag(),
+            "separators" => self.separators = flag(),
+            _ => return false
+        };
+
+        true
+    }
+}
 
-                    while let Some(mut c) = chars.next()
-                    {
-                        if c == ' '
-                        {
-                            c = chars.next_value().to_ascii_uppercase();
-                        }
+//=======================================================================//
 
-                        tool.push(c);
-                    }
+/// An entry of a section of [`color_enum`].
+struct ColorEntry
+{
+    /// The variants sharing the entry, separated by `|`.
+    variants:    Vec<String>,
+    /// The variants declared as aliases of one of `variants` through `alias B = A`.
+    aliases:     Vec<String>,
+    /// The draw height pinned through `= height`, if any.
+    height:      Option<f32>,
+    /// The default color set through `= #rrggbb`, if any.
+    color:       Option<[u8; 4]>,
+    /// The default color of the light theme set through a second `#rrggbb`, if any.
+    light_color: Option<[u8; 4]>
+}
 
-                    string.push_str(&format!(
-                        "Tool::{tool}.header(),\n|ui| {{\nui.vertical(|ui| \
-                         {{\ntools_buttons.image(ui, Tool::{tool});\n"
-                    ));
-                },
-                ManualItem::Texture => unreachable!()
-            };
-        },
-        |string, name, file, item| {
-            let processed = file
-                .trim()
-                .replace("### ", "")
-                .replace("```ini", "")
-                .replace('\"', "\\\"")
-                .replace("   ", "")
-                .replace('`', "");
+impl ColorEntry
+{
+    /// Returns the match pattern of the variants of the entry.
+    #[inline]
+    #[must_use]
+    fn pattern(&self) -> String
+    {
+        self.all_variants()
+            .map(|variant| format!("Self::{variant}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
 
+    /// Returns an iterator to the variants of the entry followed by their aliases.
+    #[inline]
+    fn all_variants(&self) -> impl Iterator<Item = &String>
+    {
+        self.variants.iter().chain(&self.aliases)
+    }
+}
+
+//=======================================================================//
+
+/// A variant of the `Bind` enum generated by [`bind_enum`].
+struct BindEntry
+{
+    /// The outer attributes.
+    attributes:  String,
+    /// The `#[cfg]` attributes.
+    cfg:         String,
+    /// The name.
+    ident:       String,
+    /// The text representing the bind in UI elements.
+    label:       String,
+    /// The key used in the config file.
+    key:         String,
+    /// The default keycodes set through `= KeyCode`, or `= KeyCode > KeyCode` for a chord.
+    keycodes:    Vec<String>,
+    /// The category set through a `category:` header, if any.
+    category:    Option<String>,
+    /// The escaped description, either written as a string literal following the name or the
+    /// default keycode, or taken from the doc comments.
+    description: String
+}
+
+impl BindEntry
+{
+    /// Returns a new [`BindEntry`] from `segment`, the tokens of a bind in the input of
+    /// [`bind_enum`], belonging to `category`.
+    /// # Panics
+    /// Panics if `segment` is not properly formatted.
+    #[inline]
+    #[must_use]
+    fn new(segment: Vec<TokenTree>, category: Option<String>) -> Self
+    {
+        let mut attributes = String::new();
+        let mut groups = Vec::new();
+        let mut cfg = String::new();
+        let mut iter = segment.into_iter();
+
+        while let Some(item) = iter.next()
+        {
             match item
             {
-                ManualItem::Regular =>
+                TokenTree::Punct(p) if p.as_char() == '#' =>
                 {
-                    let mut lines = processed.lines();
-                    let command = lines.next_value();
-                    let mut exp = String::new();
+                    let group = match_or_panic!(iter.next_value(), TokenTree::Group(g), g);
+                    let attribute = format!("#{group}\n");
 
-                    for line in lines
+                    if find_attribute(std::slice::from_ref(&group), "cfg").is_some()
                     {
-                        exp.push_str(line);
-                        exp.push('\n');
+                        cfg.push_str(&attribute);
                     }
 
-                    exp.pop();
-                    string.push_str(&format!(
-                        "show_explanation(ui, |ui| {{ ui.label(\"{command}\"); }}, \"{exp}\");\n"
-                    ));
+                    attributes.push_str(&attribute);
+                    groups.push(group);
                 },
-                ManualItem::Tool =>
+                TokenTree::Ident(ident) =>
                 {
-                    let mut chars = name.chars();
-                    let mut subtool = chars.next_value().to_ascii_uppercase().to_string();
+                    let mut keycodes = Vec::new();
+                    let mut description = doc_description(&groups);
 
-                    while let Some(mut c) = chars.next()
+                    while let Some(item) = iter.next()
                     {
-                        if c == '_'
+                        match item
                         {
-                            c = chars.next_value().to_ascii_uppercase();
+                            TokenTree::Punct(p)
+                                if p.as_char() == '=' ||
+                                    (p.as_char() == '>' && !keycodes.is_empty()) =>
+                            {
+                                keycodes.push(iter.next_value().to_string());
+                            },
+                            TokenTree::Literal(literal) =>
+                            {
+                                let literal = literal.to_string();
+                                description = literal
+                                    .strip_prefix('"')
+                                    .and_then(|literal| literal.strip_suffix('"'))
+                                    .unwrap_or_else(|| panic!("Invalid description {literal}."))
+                                    .to_string();
+                            },
+                            item => panic!("Invalid bind {ident} {item}.")
                         }
-
-                        subtool.push(c);
                     }
 
-                    let mut lines = processed.lines();
-                    let mut exp = lines.next_value().to_string();
-                    exp.push_str(" (");
-                    exp.push_str(
-                        &std::fs::read_to_string(format!("{path}/docs/subtools binds/{name}.md"))
-                            .unwrap()
+                    assert!(
+                        keycodes.len() <= MAX_CHORD_LEN,
+                        "The chord of bind {ident} is longer than {MAX_CHORD_LEN} keys."
                     );
-                    exp.push_str(")\n");
-
-                    for line in lines
-                    {
-                        exp.push_str(line);
-                        exp.push('\n');
-                    }
-
-                    exp.pop();
 
-                    string.push_str(&format!(
-                        "show_explanation(ui, |ui| {{ tools_buttons.image(ui, \
-                         SubTool::{subtool}); }}, \"{exp}\");\n"
-                    ));
+                    let ident = ident.to_string();
+
+                    return Self {
+                        attributes,
+                        cfg,
+                        label: label(&ident),
+                        key: snake_case(&ident),
+                        ident,
+                        keycodes,
+                        category,
+                        description
+                    };
                 },
-                ManualItem::Texture =>
-                {
-                    string.push_str(&format!(
-                        "show_explanation(ui, |ui| {{ ui.label(\"TEXTURE EDITING\"); }}, \
-                         \"{processed}\");\n"
-                    ));
-                }
-            };
-        },
-        |string, last| {
-            string.push_str("})\n});\n\n");
-
-            if !last
-            {
-                string.push_str("ui.separator();\n\n");
+                item => panic!("Invalid bind {item}.")
             }
         }
-    );
 
-    format!("{SHOW_EXPLANATION}\n\n{body}").parse().unwrap()
+        panic!("Bind without name.");
+    }
 }
 
+//=======================================================================//
+// FUNCTIONS
+//
 //=======================================================================//
 
-/// Generates a function which associates a f32 value representing a certain height to each provided
-/// enum match arm.
-#[allow(clippy::missing_panics_doc)]
-#[proc_macro]
-pub fn color_enum(stream: TokenStream) -> TokenStream
+/// Checks whever `value` is a comma.
+/// # Panics
+/// Function panics if `value` is not a comma.
+#[inline]
+fn is_comma(value: TokenTree)
 {
-    #[inline]
-    fn is_column<I: Iterator<Item = TokenTree>>(stream: &mut I)
-    {
-        assert!(match_or_panic!(stream.next_value(), TokenTree::Punct(p), p).as_char() == ':');
-    }
+    assert!(match_or_panic!(value, TokenTree::Punct(p), p).as_char() == ',');
+}
 
-    #[inline]
-    fn push_key_and_label(item: &str, label_func: &mut String, key_func: &mut String)
-    {
-        let mut chars = item.chars();
-        let c = chars.next_value();
-        key_func.push_str(&format!("Self::{item} => \"{}", c.to_ascii_lowercase()));
-        label_func.push_str(&format!("Self::{item} => \"{c}"));
+//=======================================================================//
 
-        for c in chars
-        {
-            if c.is_uppercase()
-            {
-                key_func.push('_');
-                key_func.push(c.to_ascii_lowercase());
+/// Checks whever `value` is a colon.
+/// # Panics
+/// Function panics if `value` is not a colon.
+#[inline]
+fn is_column(value: TokenTree)
+{
+    assert!(match_or_panic!(value, TokenTree::Punct(p), p).as_char() == ':');
+}
 
-                label_func.push(' ');
-                label_func.push(c);
+//=======================================================================//
 
-                continue;
-            }
+/// Returns the arguments of the attribute named `name` among `attributes`, if any. An attribute
+/// without arguments returns an empty stream.
+#[must_use]
+fn find_attribute(attributes: &[Group], name: &str) -> Option<TokenStream>
+{
+    attributes.iter().find_map(|attribute| {
+        let mut iter = attribute.stream().into_iter();
 
-            for func in [&mut *key_func, &mut *label_func]
-            {
-                func.push(c);
-            }
+        if iter.next_value().to_string() != name
+        {
+            return None;
         }
 
-        for func in [key_func, label_func]
+        match iter.next()
         {
-            func.push_str("\",\n");
+            Some(TokenTree::Group(g)) => g.stream(),
+            _ => TokenStream::new()
         }
-    }
+        .into()
+    })
+}
 
-    #[inline]
-    #[must_use]
-    fn extract<I: Iterator<Item = TokenTree>>(
-        stream: &mut I,
-        end_tag: &str,
-        label_func: &mut String,
-        key_func: &mut String
-    ) -> Vec<String>
-    {
-        let mut vec: Vec<String> = Vec::new();
+//=======================================================================//
 
-        while let Some(item) = stream.next()
+/// Splits `stream` at the commas which are not nested inside angle brackets. Empty segments, such
+/// as the one following a trailing comma, are discarded.
+#[must_use]
+fn split_at_commas(stream: TokenStream) -> Vec<Vec<TokenTree>>
+{
+    let mut segments = vec![Vec::new()];
+    let mut depth = 0usize;
+    let mut arrow = false;
+
+    for item in stream
+    {
+        if let TokenTree::Punct(p) = &item
         {
-            if let TokenTree::Punct(p) = item
+            match p.as_char()
             {
-                let c = p.as_char();
-
-                match c
+                ',' if depth == 0 =>
                 {
-                    ',' => (),
-                    '|' =>
-                    {
-                        let last = vec.last_mut().unwrap();
-                        let item = stream.next_value().to_string();
-                        push_key_and_label(&item, label_func, key_func);
-                        last.push_str(&format!(" | Self::{item}"));
-                    },
-                    _ => panic!()
-                }
+                    segments.push(Vec::new());
+                    continue;
+                },
+                '<' => depth += 1,
+                // The '>' of '->' does not close an angle bracket.
+                '>' if !arrow => depth = depth.saturating_sub(1),
+                _ => ()
+            };
 
-                continue;
-            }
+            arrow = p.as_char() == '-';
+        }
+        else
+        {
+            arrow = false;
+        }
 
-            let item = item.to_string();
+        segments.last_mut().unwrap().push(item);
+    }
 
-            if item == end_tag
-            {
-                is_column(stream);
-                break;
-            }
+    segments.retain(|segment| !segment.is_empty());
+    segments
+}
 
-            push_key_and_label(&item, label_func, key_func);
-            vec.push(format!("Self::{item}"));
-        }
+//=======================================================================//
 
-        vec
-    }
+/// Returns the fields contained in `group`, the body of a tuple or struct variant.
+/// # Panics
+/// Panics if a named field has no name.
+#[must_use]
+fn variant_fields(group: &Group) -> Fields
+{
+    let named = group.delimiter() == Delimiter::Brace;
+    let mut unnamed = Vec::new();
+    let mut fields = Vec::new();
 
-    #[inline]
-    #[must_use]
-    fn generate_height_func<'a, I: Iterator<Item = &'a str>>(
-        start: &str,
-        mut start_height: f32,
-        interval: f32,
-        iter: I
-    ) -> (String, f32)
+    for segment in split_at_commas(group.stream())
     {
-        let mut height_func = start.to_string();
+        let mut iter = segment.into_iter().peekable();
+
+        // Skip attributes and visibility.
+        loop
+        {
+            match iter.peek()
+            {
+                Some(TokenTree::Punct(p)) if p.as_char() == '#' =>
+                {
+                    iter.next();
+                    iter.next();
+                },
+                Some(TokenTree::Ident(ident)) if &ident.to_string() == "pub" =>
+                {
+                    iter.next();
 
-        for item in iter
+                    if let Some(TokenTree::Group(g)) = iter.peek()
+                    {
+                        if g.delimiter() == Delimiter::Parenthesis
+                        {
+                            iter.next();
+                        }
+                    }
+                },
+                _ => break
+            };
+        }
+
+        if !named
         {
-            height_func.push_str(&format!("{item} => {start_height}f32,\n"));
-            start_height += interval;
+            unnamed.push(iter.collect::<TokenStream>().to_string());
+            continue;
         }
 
-        height_func.push_str("_ => panic!(\"Invalid color: {self:?}\")\n}\n}");
-        (height_func, start_height)
+        let name = match iter.next()
+        {
+            Some(TokenTree::Ident(ident)) => ident.to_string(),
+            _ => panic!("Field has no name.")
+        };
+
+        is_column(iter.next_value());
+        fields.push((name, iter.collect::<TokenStream>().to_string()));
     }
 
-    let textures_interval = f32::from(*TEXTURE_HEIGHT_RANGE.end());
-    let mut stream = stream.into_iter();
+    if named
+    {
+        Fields::Named(fields)
+    }
+    else
+    {
+        Fields::Unnamed(unnamed)
+    }
+}
 
-    let mut key_func = "
-    /// The config file key relative to the drawn color associated with [`Color`].
-    #[inline]
-    #[must_use]
-    pub const fn config_file_key(self) -> &'static str
+//=======================================================================//
+
+/// Parses the integer literal `literal` of an explicit discriminant.
+/// # Panics
+/// Panics if `literal` is not an integer literal.
+#[must_use]
+fn parse_discriminant(literal: &str) -> i128
+{
+    let literal = literal.replace('_', "");
+
+    let (radix, digits) = match literal.get(..2)
     {
-        match self
-        {
-    "
-    .to_string();
+        Some("0x") => (16, &literal[2..]),
+        Some("0o") => (8, &literal[2..]),
+        Some("0b") => (2, &literal[2..]),
+        _ => (10, literal.as_str())
+    };
 
-    let mut label_func = "
-    /// The text label representing [`Color`] in UI elements.
-    #[inline]
-    #[must_use]
-    pub const fn label(self) -> &'static str
+    // Remove the type suffix, if any.
+    let digits = match digits.find(['i', 'u'])
     {
-        match self
-        {
-    "
-    .to_string();
+        Some(i) => &digits[..i],
+        None => digits
+    };
 
-    assert!(stream.next_value().to_string() == "clear");
-    is_column(&mut stream);
-    let clear = stream.next_value().to_string();
-    push_key_and_label(&clear, &mut label_func, &mut key_func);
-    is_comma(stream.next_value());
+    i128::from_str_radix(digits, radix)
+        .unwrap_or_else(|_| panic!("Discriminant {literal} is not an integer literal."))
+}
 
-    assert!(stream.next_value().to_string() == "extensions");
-    is_column(&mut stream);
-    let extensions = stream.next_value().to_string();
-    push_key_and_label(&extensions, &mut label_func, &mut key_func);
-    let extensions = format!("Self::{extensions}");
-    is_comma(stream.next_value());
+//=======================================================================//
 
-    assert!(stream.next_value().to_string() == "grid");
-    is_column(&mut stream);
-    let grid = extract(&mut stream, "entities", &mut label_func, &mut key_func);
-    let entities = extract(&mut stream, "ui", &mut label_func, &mut key_func);
-    let ui = extract(&mut stream, "", &mut label_func, &mut key_func);
+/// Returns the variants of the enum whose body is `group`.
+/// # Panics
+/// Panics if `group` is not a `TokenTree::Group(_)` or an explicit discriminant is not an integer
+/// literal.
+#[must_use]
+fn enum_variants(group: TokenTree) -> Vec<Variant>
+{
+    let mut variants = Vec::<Variant>::new();
+    let mut attributes = Vec::new();
+    let mut implicit = (0, None);
+    let mut iter = match_or_panic!(group, TokenTree::Group(g), g).stream().into_iter();
 
-    for func in [&mut key_func, &mut label_func]
+    while let Some(item) = iter.next()
     {
-        func.push_str("}\n}");
+        match item
+        {
+            TokenTree::Punct(p) if p.as_char() == '#' =>
+            {
+                attributes.push(match_or_panic!(iter.next_value(), TokenTree::Group(g), g));
+            },
+            TokenTree::Punct(p) if p.as_char() == ',' => (),
+            TokenTree::Group(g) => variants.last_mut().unwrap().fields = variant_fields(&g),
+            TokenTree::Punct(p) if p.as_char() == '=' =>
+            {
+                let mut value = iter.next_value().to_string();
+
+                if value == "-"
+                {
+                    value.push_str(&iter.next_value().to_string());
+                }
+
+                let discriminant = match value.strip_prefix('-')
+                {
+                    Some(value) => -parse_discriminant(value),
+                    None => parse_discriminant(&value)
+                };
+
+                let variant = variants.last_mut().unwrap();
+                variant.discriminant = discriminant;
+                variant.cfg_discriminant = None;
+            },
+            TokenTree::Ident(ident) =>
+            {
+                if let Some(variant) = variants.last()
+                {
+                    implicit = variant.next_discriminant(&implicit);
+                }
+
+                variants.push(Variant {
+                    attributes: std::mem::take(&mut attributes),
+                    ident,
+                    fields: Fields::Unit,
+                    discriminant: implicit.0,
+                    cfg_discriminant: implicit.1.clone()
+                });
+            },
+            item => panic!("Unexpected token {item}.")
+        }
     }
 
-    let (height_func, clip_height) = generate_height_func(
-        "
-    /// The height at which map elements colored with a certain [`Color`] should be drawn.
-    #[inline]
-    #[must_use]
-    pub fn entity_height(self) -> f32
-    {
-        match self
-        {",
-        1f32,
-        textures_interval + 1f32,
-        entities.iter().map(String::as_str)
-    );
+    variants
+}
 
-    let (line_height_func, thing_angle_height) = generate_height_func(
-        "
-    /// The draw height of the lines.
-    #[inline]
-    #[must_use]
-    pub fn line_height(self) -> f32
+//=======================================================================//
+
+/// Returns the expression evaluating to the amount of `variants` which are compiled, as a `ty`.
+/// The expression contains no macros so that it can be used in the types of derived structs.
+#[must_use]
+fn variants_count<'a>(variants: impl IntoIterator<Item = &'a Variant>, ty: &str) -> String
+{
+    let mut count = 0;
+    let mut conditional = String::new();
+
+    for variant in variants
     {
-        match self
+        match variant.cfg()
         {
-    ",
-        clip_height + 1f32,
-        1f32,
-        grid.iter()
-            .chain(Some(&extensions).iter().copied())
-            .chain(&entities)
-            .chain(&ui)
-            .map(String::as_str)
-    );
+            Some(predicate) =>
+            {
+                conditional.push_str(&format!("#[cfg({predicate})] let count = count + 1; "));
+            },
+            None => count += 1
+        };
+    }
+
+    if conditional.is_empty()
+    {
+        count.to_string()
+    }
+    else
+    {
+        format!("{{ let count: {ty} = {count}; {conditional}count }}")
+    }
+}
+
+//=======================================================================//
+
+/// Returns the pattern of a match arm checking whether `binding` is equal to the constant
+/// expression `expr`.
+#[inline]
+#[must_use]
+fn expr_pattern(binding: &str, expr: &str) -> String
+{
+    if expr.parse::<i128>().is_ok()
+    {
+        expr.to_string()
+    }
+    else
+    {
+        format!("{binding} if {binding} == {expr}")
+    }
+}
+
+//=======================================================================//
+
+/// Returns the type of the literal `literal`, using its suffix if any, otherwise `f32` for floats
+/// and `i32` for integers. The hexadecimal, octal, and binary literals cannot have a float suffix,
+/// so `0x1f32` is an `i32`.
+/// # Panics
+/// Panics if `literal` is not a string, char, bool, or number literal.
+#[must_use]
+fn literal_type(literal: &str) -> &'static str
+{
+    const SUFFIXES: [&str; 14] = [
+        "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64",
+        "u128", "usize"
+    ];
+
+    let literal = literal.trim_start_matches('-').trim_start();
+
+    if literal.starts_with('"') || literal.starts_with("r\"") || literal.starts_with("r#")
+    {
+        return "&'static str";
+    }
+
+    if literal.starts_with('\'')
+    {
+        return "char";
+    }
+
+    if literal == "true" || literal == "false"
+    {
+        return "bool";
+    }
+
+    assert!(
+        literal.starts_with(|c: char| c.is_ascii_digit()),
+        "Unsupported literal {literal}."
+    );
+
+    let hex = literal.starts_with("0x") || literal.starts_with("0X");
+    let prefixed = hex || literal.starts_with("0o") || literal.starts_with("0b");
+
+    if let Some(suffix) = SUFFIXES
+        .into_iter()
+        .skip(usize::from(prefixed) * 2)
+        .find(|suffix| literal.ends_with(suffix))
+    {
+        return suffix;
+    }
+
+    if literal.contains('.') || (!hex && literal.contains(['e', 'E']))
+    {
+        "f32"
+    }
+    else
+    {
+        "i32"
+    }
+}
+
+//=======================================================================//
+
+/// Returns the `const` qualifier for the functions taking or building `variants` by value, which
+/// can only be const if the variants have no payloads, since these may not have trivial drops.
+#[inline]
+#[must_use]
+fn constness(variants: &[Variant]) -> &'static str
+{
+    if variants.iter().all(|v| matches!(v.fields, Fields::Unit))
+    {
+        "const "
+    }
+    else
+    {
+        ""
+    }
+}
+
+//=======================================================================//
+
+/// Returns `ident` without the `r#` prefix of raw identifiers, to be used in strings and in the
+/// names of generated items.
+#[inline]
+#[must_use]
+fn unraw(ident: &str) -> &str { ident.strip_prefix("r#").unwrap_or(ident) }
+
+//=======================================================================//
+
+/// Converts the CamelCase identifier `ident` to snake_case.
+#[inline]
+#[must_use]
+fn snake_case(ident: &str) -> String
+{
+    let mut chars = unraw(ident).chars();
+    let mut value = chars
+        .next()
+        .map(|c| c.to_ascii_lowercase())
+        .into_iter()
+        .collect::<String>();
+
+    for ch in chars
+    {
+        if ch.is_ascii_uppercase()
+        {
+            value.push('_');
+        }
+
+        value.push(ch.to_ascii_lowercase());
+    }
+
+    value
+}
+
+//=======================================================================//
+
+/// Converts the CamelCase identifier `ident` to a label with its words separated by spaces.
+#[inline]
+#[must_use]
+fn label(ident: &str) -> String
+{
+    let ident = unraw(ident);
+    let mut value = String::with_capacity(ident.len() * 2);
+
+    for ch in ident.chars()
+    {
+        if ch.is_ascii_uppercase() && !value.is_empty()
+        {
+            value.push(' ');
+        }
+
+        value.push(ch);
+    }
+
+    value
+}
+
+//=======================================================================//
+
+/// Converts `value` to a CamelCase identifier, treating every character that is not alphanumeric
+/// as a word separator.
+/// # Panics
+/// Panics if the resulting identifier is empty or starts with a digit.
+#[inline]
+#[must_use]
+fn camel_case(value: &str) -> String
+{
+    let mut ident = String::with_capacity(value.len());
+
+    for word in value.split(|c: char| !c.is_alphanumeric())
+    {
+        let mut chars = word.chars();
+        ident.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        ident.extend(chars);
+    }
+
+    assert!(
+        ident.chars().next().is_some_and(|c| !c.is_ascii_digit()),
+        "Cannot derive an identifier from {value:?}."
+    );
+    ident
+}
+
+//=======================================================================//
+
+/// Extracts the name of an enum for `iter`, without the `r#` prefix of raw identifiers.
+/// # Panics
+/// Panics if `iter` does not belong to an enum.
+#[inline]
+#[must_use]
+fn enum_ident(iter: &mut impl Iterator<Item = TokenTree>) -> String
+{
+    for item in iter.by_ref()
+    {
+        let ident = continue_if_no_match!(item, TokenTree::Ident(ident), ident);
+
+        if &ident.to_string() == "enum"
+        {
+            let ident = match_or_panic!(iter.next_value(), TokenTree::Ident(i), i).to_string();
+            return unraw(&ident).to_string();
+        }
+    }
+
+    panic!();
+}
+
+//=======================================================================//
+
+/// Implements a constant representing the size of the `input` enum.
+/// If the variants have no fields and at least one of them is always compiled, the `FIRST` and
+/// `LAST` constants, representing the first and last variants in declaration order, are
+/// implemented as well.
+/// Variants annotated with `#[enum_skip]` are not counted.
+/// The constants are `pub` unless the enum is annotated with `#[enum_vis(...)]`, which sets the
+/// visibility of everything generated by the enum derives.
+#[proc_macro_derive(EnumSize, attributes(enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_size(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let variants = enum_data.variants;
+    let mut constants = format!("{vis}const SIZE: usize = {};", variants_count(&variants, "usize"));
+
+    if variants.iter().any(|v| v.cfg().is_none()) &&
+        variants.iter().all(|v| matches!(v.fields, Fields::Unit))
+    {
+        // The array cannot be empty since at least one variant is always compiled.
+        let mut values = String::new();
+
+        for variant in &variants
+        {
+            values.push_str(&format!("{}Self::{}, ", variant.cfg_attribute(), variant.ident));
+        }
+
+        constants.push_str(&format!(
+            "
+            /// The first variant, in declaration order.
+            {vis}const FIRST: Self = match [{values}] {{ [first, ..] => first }};
+
+            /// The last variant, in declaration order.
+            {vis}const LAST: Self = match [{values}] {{ [.., last] => last }};
+            "
+        ));
+    }
+
+    format!("{impl_header} {{ {constants} }}").parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `From<usize>` for `enum_data`, and `From<repr>` if `repr` is specified, converting
+/// the out of range values according to the `wrapping` or `saturating` `mode`.
+/// # Panics
+/// Panics if the enum is empty, has skipped variants, or has discriminants which are not contiguous
+/// starting from zero.
+#[must_use]
+fn infallible_from_usize(enum_data: &Enum, repr: Option<&str>, mode: &str) -> TokenStream
+{
+    let variants = &enum_data.variants;
+    assert!(!variants.is_empty(), "The {mode} conversion requires at least one variant.");
+
+    let len = variants_count(variants, "usize");
+    let mut arms = String::new();
+
+    for (i, variant) in variants.iter().enumerate()
+    {
+        let ident = &variant.ident;
+        assert!(!variant.skipped(), "The {mode} conversion does not support skipped variants.");
+        assert!(
+            variant.discriminant == i128::try_from(i).unwrap(),
+            "Variant {ident} breaks the contiguous discriminants required by the {mode} \
+             conversion."
+        );
+
+        arms.push_str(&format!(
+            "{}{} => {},\n",
+            variant.cfg_attribute(),
+            expr_pattern("index", &variants_count(&variants[..i], "usize")),
+            variant.constructor("Self")
+        ));
+    }
+
+    let index = if mode == "wrapping"
+    {
+        format!("value % {len}")
+    }
+    else
+    {
+        format!("value.min({len} - 1)")
+    };
+
+    let mut from_impl = format!(
+        "
+        {}
+        {{
+            #[inline]
+            fn from(value: usize) -> Self
+            {{
+                match {index}
+                {{
+                    {arms}
+                    _ => unreachable!()
+                }}
+            }}
+        }}
+        ",
+        enum_data.impl_header("From<usize>")
+    );
+
+    if let Some(repr) = repr
+    {
+        from_impl.push_str(&format!(
+            "
+            {}
+            {{
+                #[inline]
+                fn from(value: {repr}) -> Self {{ Self::from(usize::from(value)) }}
+            }}
+            ",
+            enum_data.impl_header(&format!("From<{repr}>"))
+        ));
+    }
+
+    from_impl.parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `TryFrom<usize>` for a plain enum, mapping each discriminant to its variant. The
+/// conversion error is a generated `<enum name>FromUsizeError` struct, with the same visibility as
+/// the enum, holding the value that does not match any variant.
+/// If the enum is annotated with `#[repr(u8)]` or `#[repr(u16)]` the conversion from that integer
+/// type is implemented as well.
+/// Only the last variants can be annotated with `#[enum_skip]`, so that the converted indexes
+/// match the ones of [`EnumIter`] and [`EnumSize`], and their discriminants are treated as invalid
+/// values.
+/// The behavior for out of range values can be picked with `#[enum_from_usize(mode)]`:
+/// - `strict`, the default, returns an error;
+/// - `wrapping` implements `From<usize>` instead, wrapping the value around the amount of variants;
+/// - `saturating` implements `From<usize>` instead, returning the last variant.
+///
+/// # Panics
+/// Panics if `input` does not belong to an enum, a skipped variant is followed by one which is not
+/// skipped, the mode is unknown, or a non strict mode is used on an enum that is empty, has skipped
+/// variants, or has discriminants which are not contiguous starting from zero.
+#[proc_macro_derive(
+    EnumFromUsize,
+    attributes(enum_from_usize, enum_default_payload, enum_skip, enum_vis)
+)]
+#[must_use]
+pub fn enum_from_usize(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+
+    if let Some(i) = enum_data.variants.iter().position(Variant::skipped)
+    {
+        if let Some(variant) = enum_data.variants[i..].iter().find(|v| !v.skipped())
+        {
+            panic!(
+                "Variant {} follows a skipped variant, EnumFromUsize only supports skipping the \
+                 last variants.",
+                variant.ident
+            );
+        }
+    }
+
+    let repr = enum_data.attribute("repr").and_then(|stream| {
+        stream
+            .into_iter()
+            .map(|item| item.to_string())
+            .find(|item| item == "u8" || item == "u16")
+    });
+
+    match enum_data
+        .attribute("enum_from_usize")
+        .map(|stream| stream.to_string())
+        .as_deref()
+    {
+        None | Some("strict") => (),
+        Some(mode @ ("wrapping" | "saturating")) =>
+        {
+            return infallible_from_usize(&enum_data, repr.as_deref(), mode);
+        },
+        Some(mode) => panic!("Unknown enum_from_usize mode {mode}.")
+    };
+
+    let enum_data = enum_data.without_skipped();
+    let usize_header = enum_data.impl_header("TryFrom<usize>");
+    let repr = repr.map(|repr| (enum_data.impl_header(&format!("TryFrom<{repr}>")), repr));
+
+    let Enum {
+        visibility,
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let enum_name = unraw(&enum_ident.to_string()).to_string();
+    let error_ident = format!("{enum_name}FromUsizeError");
+    let mut arms = String::new();
+
+    for variant in variants
+    {
+        // Negative discriminants cannot be represented by a `usize`.
+        if variant.discriminant >= 0
+        {
+            arms.push_str(&format!(
+                "{}{} => Ok({}),\n",
+                variant.cfg_attribute(),
+                expr_pattern("value", &variant.discriminant_expr()),
+                variant.constructor("Self")
+            ));
+        }
+    }
+
+    let mut from_impl = format!(
+        "
+        /// The error returned when an integer does not match any [`{enum_ident}`] variant.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        {visibility}struct {error_ident}(pub usize);
+
+        impl std::fmt::Display for {error_ident}
+        {{
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+            {{
+                write!(f, \"{{}} is not a valid {enum_name} index\", self.0)
+            }}
+        }}
+
+        impl std::error::Error for {error_ident} {{}}
+
+        {usize_header}
+        {{
+            type Error = {error_ident};
+
+            #[inline]
+            fn try_from(value: usize) -> Result<Self, Self::Error>
+            {{
+                match value
+                {{
+                    {arms}
+                    _ => Err({error_ident}(value))
+                }}
+            }}
+        }}
+        "
+    );
+
+    if let Some((repr_header, repr)) = repr
+    {
+        from_impl.push_str(&format!(
+            "
+            {repr_header}
+            {{
+                type Error = {error_ident};
+
+                #[inline]
+                fn try_from(value: {repr}) -> Result<Self, Self::Error>
+                {{
+                    match value
+                    {{
+                        {arms}
+                        _ => Err({error_ident}(usize::from(value)))
+                    }}
+                }}
+            }}
+            "
+        ));
+    }
+
+    from_impl.parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Implements methods that return iterators to the values of a plain enum, either all of them or
+/// the ones starting from a certain variant.
+/// Variants annotated with `#[enum_skip]` are not returned, iterating from one of them starts from
+/// the following variant.
+#[proc_macro_derive(EnumIter, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_iter(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let Enum {
+        ident: enum_ident,
+        generics:
+            Generics {
+                params,
+                args,
+                where_clause
+            },
+        variants,
+        ..
+    } = enum_data;
+    let enum_ident = enum_ident.to_string();
+    let mut enum_match = String::new();
+    let mut start_match = String::new();
+    let mut listed = Vec::new();
+
+    // The iterator walks the variants in declaration order, regardless of their discriminants.
+    for variant in &variants
+    {
+        let cfg = variant.cfg_attribute();
+        let position = variants_count(listed.iter().copied(), "usize");
+        start_match.push_str(&format!("{cfg}{} => {position},\n", variant.pattern("Self")));
+
+        if variant.skipped()
+        {
+            continue;
+        }
+
+        enum_match.push_str(&format!(
+            "{cfg}{} => {},\n",
+            expr_pattern("index", &position),
+            variant.constructor(&enum_ident)
+        ));
+        listed.push(variant);
+    }
+
+    enum_match.push_str("_ => unreachable!()");
+    let enum_len = variants_count(listed, "usize");
+    // An empty enum cannot be matched without making the rest of the function unreachable.
+    let iter_from = if variants.is_empty()
+    {
+        "let _ = start;\nSelf::iter()".to_string()
+    }
+    else
+    {
+        format!(
+            "
+            let position: usize = match start
+            {{
+                {start_match}
+            }};
+            let mut iter = Self::iter();
+
+            if let Some(skipped) = position.checked_sub(1)
+            {{
+                iter.nth(skipped);
+            }}
+
+            iter
+            "
+        )
+    };
+
+    format!(
+        "
+        {impl_header}
+        {{
+            #[inline]
+            {vis}fn iter() -> impl ExactSizeIterator<Item = Self> + std::iter::FusedIterator
+            {{
+                struct EnumIterator{params}(
+                    usize,
+                    usize,
+                    std::marker::PhantomData<fn() -> {enum_ident}{args}>
+                ) {where_clause};
+
+                impl{params} EnumIterator{args} {where_clause}
+                {{
+                    #[inline]
+                    #[must_use]
+                    fn variant(index: usize) -> {enum_ident}{args}
+                    {{
+                        match index
+                        {{
+                            {enum_match}
+                        }}
+                    }}
+                }}
+
+                impl{params} ExactSizeIterator for EnumIterator{args} {where_clause}
+                {{
+                    #[inline]
+                    #[must_use]
+                    fn len(&self) -> usize {{ self.1 - self.0 }}
+                }}
+
+                impl{params} Iterator for EnumIterator{args} {where_clause}
+                {{
+                    type Item = {enum_ident}{args};
+
+                    #[inline]
+                    fn next(&mut self) -> Option<Self::Item>
+                    {{
+                        if self.0 == self.1
+                        {{
+                            return None;
+                        }}
+
+                        let value = Self::variant(self.0);
+                        self.0 += 1;
+                        Some(value)
+                    }}
+
+                    #[inline]
+                    fn nth(&mut self, n: usize) -> Option<Self::Item>
+                    {{
+                        self.0 = self.0.saturating_add(n).min(self.1);
+                        self.next()
+                    }}
+
+                    #[inline]
+                    fn last(self) -> Option<Self::Item>
+                    {{
+                        (self.0 != self.1).then(|| Self::variant(self.1 - 1))
+                    }}
+
+                    #[inline]
+                    fn count(self) -> usize {{ self.len() }}
+
+                    #[inline]
+                    fn size_hint(&self) -> (usize, Option<usize>)
+                    {{
+                        let len = self.len();
+                        (len, Some(len))
+                    }}
+                }}
+
+                impl{params} std::iter::FusedIterator for EnumIterator{args} {where_clause} {{}}
+
+                EnumIterator(0, {enum_len}, std::marker::PhantomData)
+            }}
+
+            /// Returns an iterator to the values starting from `start`, in declaration order.
+            #[inline]
+            {vis}fn iter_from(start: Self)
+                -> impl ExactSizeIterator<Item = Self> + std::iter::FusedIterator
+            {{
+                {iter_from}
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements a method that returns a random value of a plain enum. The randomness is provided by
+/// a callback, which is called with the amount of variants and must return an index smaller than
+/// it, so that no RNG dependency is required.
+/// Variants annotated with `#[enum_skip]` are never returned.
+#[proc_macro_derive(EnumRandom, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_random(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let mut random_match = String::new();
+
+    for (i, variant) in variants.iter().enumerate()
+    {
+        random_match.push_str(&format!(
+            "{}{} => {},\n",
+            variant.cfg_attribute(),
+            expr_pattern("index", &variants_count(&variants[..i], "usize")),
+            variant.constructor("Self")
+        ));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns a random variant of [`{enum_ident}`]. `rng` is called with the amount of
+            /// variants and must return an index smaller than it.
+            /// # Panics
+            /// Panics if the index returned by `rng` is out of bounds.
+            #[inline]
+            #[must_use]
+            {vis}fn random(rng: &mut impl FnMut(usize) -> usize) -> Self
+            {{
+                match rng({})
+                {{
+                    {random_match}
+                    index => panic!(\"Random index {{index}} is out of bounds.\")
+                }}
+            }}
+        }}
+        ",
+        variants_count(&variants, "usize")
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements a method that returns an iterator to the values of a plain enum paired with their
+/// discriminants, which are the same indexes accepted by the `EnumFromUsize` conversion.
+/// Variants annotated with `#[enum_skip]` are not returned.
+/// # Panics
+/// Panics if `input` does not belong to an enum or a variant has a negative discriminant.
+#[proc_macro_derive(EnumEnumerate, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[must_use]
+pub fn enum_enumerate(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let variants = enum_data.variants;
+    let mut values = String::new();
+
+    for variant in &variants
+    {
+        assert!(
+            variant.discriminant >= 0,
+            "Variant {} has a negative discriminant, EnumEnumerate requires usize indexes.",
+            variant.ident
+        );
+
+        values.push_str(&format!(
+            "{}({}, {}), ",
+            variant.cfg_attribute(),
+            variant.discriminant_expr(),
+            variant.constructor("Self")
+        ));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns an iterator to the values paired with their indexes, in declaration order.
+            #[inline]
+            {vis}fn indexed_iter() -> impl ExactSizeIterator<Item = (usize, Self)>
+            {{
+                let values: [(usize, Self); {}] = [{values}];
+                values.into_iter()
+            }}
+        }}
+        ",
+        variants_count(&variants, "usize")
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements a `value()` method returning the literal associated to each variant through the
+/// `#[value(...)]` attribute, such as `#[value(3.5)]` or `#[value("name")]`.
+/// The type of the values is inferred from the literals, unless it is specified through
+/// `#[enum_table(Type)]` on the enum.
+/// Variants annotated with `#[enum_skip]` do not need a value, calling `value()` on them causes a
+/// panic.
+/// # Panics
+/// Panics if `input` does not belong to an enum, a variant has no value, or the types of the
+/// literals do not match.
+#[proc_macro_derive(EnumTable, attributes(enum_table, enum_skip, enum_vis, value))]
+#[must_use]
+pub fn enum_table(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let mut value_type = enum_data.attribute("enum_table").map(|stream| stream.to_string());
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let explicit_type = value_type.is_some();
+    let mut value_match = String::new();
+
+    for variant in &variants
+    {
+        let ident = &variant.ident;
+        let pattern = format!("{}{}", variant.cfg_attribute(), variant.pattern("Self"));
+
+        if variant.skipped()
+        {
+            value_match
+                .push_str(&format!("{pattern} => panic!(\"Variant {ident} has no value.\"),\n"));
+            continue;
+        }
+
+        let value = find_attribute(&variant.attributes, "value")
+            .unwrap_or_else(|| panic!("Variant {ident} has no #[value(...)] attribute."))
+            .to_string();
+
+        if !explicit_type
+        {
+            let ty = literal_type(&value);
+
+            match &value_type
+            {
+                Some(value_type) =>
+                {
+                    assert!(
+                        value_type == ty,
+                        "The value of variant {ident} is a {ty}, expected a {value_type}."
+                    );
+                },
+                None => value_type = Some(ty.to_string())
+            };
+        }
+
+        value_match.push_str(&format!("{pattern} => {value},\n"));
+    }
+
+    let constness = constness(&variants);
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns the value associated with the [`{enum_ident}`] variant.
+            #[inline]
+            #[must_use]
+            {vis}{constness}fn value(self) -> {}
+            {{
+                match self
+                {{
+                    {value_match}
+                }}
+            }}
+        }}
+        ",
+        value_type.unwrap_or_else(|| "()".to_string())
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `Default` for a plain enum, returning the variant annotated with `#[default]` or,
+/// if there is none, the first variant which is always compiled and not annotated with
+/// `#[enum_skip]`.
+/// # Panics
+/// Panics if `input` does not belong to an enum, more than one variant is annotated with
+/// `#[default]`, or no default variant can be picked.
+#[proc_macro_derive(EnumDefault, attributes(default, enum_default_payload, enum_skip))]
+#[must_use]
+pub fn enum_default(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("Default");
+    let variants = enum_data.variants;
+    let mut marked = variants
+        .iter()
+        .filter(|v| find_attribute(&v.attributes, "default").is_some());
+    let default = marked.next();
+    assert!(marked.next().is_none(), "More than one variant is annotated with #[default].");
+
+    let default = default
+        .or_else(|| variants.iter().find(|v| !v.skipped() && v.cfg().is_none()))
+        .expect("No variant can be the default one.");
+
+    format!(
+        "
+        {impl_header}
+        {{
+            #[inline]
+            fn default() -> Self {{ {} }}
+        }}
+        ",
+        default.constructor("Self")
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements a constant array containing the values of a plain enum, in declaration order.
+/// Variants annotated with `#[enum_skip]` are not included.
+#[proc_macro_derive(EnumVariants, attributes(enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_variants_array(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let mut values = String::new();
+
+    for variant in &variants
+    {
+        let ident = &variant.ident;
+        assert!(
+            matches!(variant.fields, Fields::Unit),
+            "Variant {ident} has fields, EnumVariants requires unit variants."
+        );
+        values.push_str(&format!("{}Self::{ident}, ", variant.cfg_attribute()));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// The values of [`{enum_ident}`], in declaration order.
+            {vis}const VARIANTS: [Self; {}] = [{values}];
+        }}
+        ",
+        variants_count(&variants, "usize")
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements the `next()` and `prev()` methods for a plain enum, returning the variants following
+/// and preceding `self` in declaration order, wrapping around at the ends.
+/// Variants annotated with `#[enum_skip]` are never returned.
+/// # Panics
+/// Panics if `input` does not belong to an enum or all its variants are skipped.
+#[proc_macro_derive(EnumCycle, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[must_use]
+pub fn enum_cycle(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let variants = enum_data.variants;
+    let listed = variants.iter().filter(|v| !v.skipped()).count();
+    assert!(listed != 0, "EnumCycle requires at least one variant which is not skipped.");
+
+    let constness = constness(&variants);
+
+    let mut next_match = String::new();
+    let mut prev_match = String::new();
+    let len = variants.len();
+
+    for (i, variant) in variants.iter().enumerate()
+    {
+        let pattern = format!("{}{}", variant.cfg_attribute(), variant.pattern("Self"));
+        let next = cycle_neighbour(variant, (1..=len).map(|j| &variants[(i + j) % len]));
+        let prev = cycle_neighbour(variant, (1..=len).map(|j| &variants[(i + len - j) % len]));
+
+        next_match.push_str(&format!("{pattern} => {next},\n"));
+        prev_match.push_str(&format!("{pattern} => {prev},\n"));
+    }
+
+    // The neighbours of the variants may be returned early depending on the `#[cfg]` attributes.
+    let lint = if variants.iter().any(|v| v.cfg().is_some())
+    {
+        "#[allow(unreachable_code)]"
+    }
+    else
+    {
+        ""
+    };
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns the variant following `self`, wrapping around after the last one.
+            #[inline]
+            #[must_use]
+            {lint}
+            {vis}{constness}fn next(self) -> Self
+            {{
+                match self
+                {{
+                    {next_match}
+                }}
+            }}
+
+            /// Returns the variant preceding `self`, wrapping around before the first one.
+            #[inline]
+            #[must_use]
+            {lint}
+            {vis}{constness}fn prev(self) -> Self
+            {{
+                match self
+                {{
+                    {prev_match}
+                }}
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Returns the expression evaluating to the first of `candidates` which is compiled and not
+/// skipped, falling back to `variant`. Candidates with `#[cfg]` attributes are returned early by
+/// statements only compiled along with them.
+#[must_use]
+fn cycle_neighbour<'a>(variant: &Variant, candidates: impl Iterator<Item = &'a Variant>) -> String
+{
+    let mut value = "{ ".to_string();
+
+    for candidate in candidates.filter(|v| !v.skipped())
+    {
+        let constructor = candidate.constructor("Self");
+
+        match candidate.cfg()
+        {
+            Some(predicate) if candidate.ident.to_string() != variant.ident.to_string() =>
+            {
+                value.push_str(&format!("#[cfg({predicate})] {{ return {constructor}; }} "));
+            },
+            _ =>
+            {
+                value.push_str(&constructor);
+                break;
+            }
+        };
+    }
+
+    value.push_str(" }");
+    value
+}
+
+//=======================================================================//
+
+/// Implements a constant array containing the names of the variants of an enum, in declaration
+/// order. Variants annotated with `#[enum_skip]` are not included.
+#[proc_macro_derive(EnumVariantNames, attributes(enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_variant_names(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let mut names = String::new();
+
+    for variant in &variants
+    {
+        names.push_str(&format!(
+            "{}\"{}\", ",
+            variant.cfg_attribute(),
+            unraw(&variant.ident.to_string())
+        ));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// The names of the variants of [`{enum_ident}`], in declaration order.
+            {vis}const NAMES: [&'static str; {}] = [{names}];
+        }}
+        ",
+        variants_count(&variants, "usize")
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `<enum name>Map<T>` struct, with the same visibility as the enum unless specified by
+/// `#[enum_vis(...)]`, storing a `T` value for each variant, which can be indexed by the variants
+/// themselves. Variants annotated with `#[enum_skip]` have no associated value, indexing the map
+/// with them causes a panic.
+/// # Panics
+/// Panics if `input` does not belong to an enum or the enum has generic parameters.
+#[proc_macro_derive(EnumMap, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[must_use]
+pub fn enum_map(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    enum_data.assert_not_generic("EnumMap");
+    let visibility = enum_data.type_visibility();
+
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let enum_ident = enum_ident.to_string();
+    let map_ident = format!("{}Map", unraw(&enum_ident));
+    let mut index_match = String::new();
+    let mut keys = String::new();
+    let mut from_fn_values = String::new();
+    let mut listed = Vec::new();
+
+    for variant in &variants
+    {
+        let cfg = variant.cfg_attribute();
+        let pattern = variant.pattern(&enum_ident);
+
+        if variant.skipped()
+        {
+            index_match.push_str(&format!(
+                "{cfg}{pattern} => panic!(\"Variant {} has no value in {map_ident}.\"),\n",
+                variant.ident
+            ));
+            continue;
+        }
+
+        let constructor = variant.constructor(&enum_ident);
+        index_match.push_str(&format!(
+            "{cfg}{pattern} => {},\n",
+            variants_count(listed.iter().copied(), "usize")
+        ));
+        keys.push_str(&format!("{cfg}{constructor}, "));
+        from_fn_values.push_str(&format!("{cfg}f({constructor}), "));
+        listed.push(variant);
+    }
+
+    let len = variants_count(listed, "usize");
+
+    format!(
+        "
+        /// A map storing a value for each [`{enum_ident}`] variant.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        {visibility}struct {map_ident}<T>([T; {len}]);
+
+        impl<T: Default> Default for {map_ident}<T>
+        {{
+            #[inline]
+            fn default() -> Self {{ Self::from_fn(|_| T::default()) }}
+        }}
+
+        impl<T> std::ops::Index<{enum_ident}> for {map_ident}<T>
+        {{
+            type Output = T;
+
+            #[inline]
+            fn index(&self, key: {enum_ident}) -> &Self::Output {{ &self.0[Self::position(key)] }}
+        }}
+
+        impl<T> std::ops::IndexMut<{enum_ident}> for {map_ident}<T>
+        {{
+            #[inline]
+            fn index_mut(&mut self, key: {enum_ident}) -> &mut Self::Output
+            {{
+                &mut self.0[Self::position(key)]
+            }}
+        }}
+
+        impl<T> {map_ident}<T>
+        {{
+            /// Returns the position of the value associated with `key`.
+            #[inline]
+            #[must_use]
+            fn position(key: {enum_ident}) -> usize
+            {{
+                match key
+                {{
+                    {index_match}
+                }}
+            }}
+
+            /// Returns the keys, in declaration order.
+            #[inline]
+            #[must_use]
+            fn keys() -> [{enum_ident}; {len}] {{ [{keys}] }}
+
+            /// Creates a new map where each value is the result of `f` called on its key.
+            #[inline]
+            #[must_use]
+            pub fn from_fn<F: FnMut({enum_ident}) -> T>(mut f: F) -> Self {{ \
+         Self([{from_fn_values}]) }}
+
+            /// Returns an iterator to the keys and the values of the map, in declaration order.
+            #[inline]
+            pub fn iter(&self) -> impl ExactSizeIterator<Item = ({enum_ident}, &T)>
+            {{
+                Self::keys().into_iter().zip(self.0.iter())
+            }}
+
+            /// Returns an iterator to the keys and the mutable values of the map, in declaration
+            /// order.
+            #[inline]
+            pub fn iter_mut(&mut self) -> impl ExactSizeIterator<Item = ({enum_ident}, &mut T)>
+            {{
+                Self::keys().into_iter().zip(self.0.iter_mut())
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `Index` and `IndexMut` of plain arrays with as many elements as the variants, so that
+/// they can be indexed by the enum itself, in declaration order.
+/// Variants annotated with `#[enum_skip]` have no associated element, indexing with them causes a
+/// panic.
+/// # Panics
+/// Panics if `input` does not belong to an enum or the enum has generic parameters.
+#[proc_macro_derive(EnumIndex, attributes(enum_skip))]
+#[must_use]
+pub fn enum_index(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    enum_data.assert_not_generic("EnumIndex");
+
+    let enum_ident = enum_data.ident.to_string();
+    let mut index_match = String::new();
+    let mut listed = Vec::new();
+
+    for variant in &enum_data.variants
+    {
+        let cfg = variant.cfg_attribute();
+        let pattern = variant.pattern(&enum_ident);
+
+        if variant.skipped()
+        {
+            index_match.push_str(&format!(
+                "{cfg}{pattern} => panic!(\"Variant {} has no associated element.\"),\n",
+                variant.ident
+            ));
+            continue;
+        }
+
+        index_match.push_str(&format!(
+            "{cfg}{pattern} => {},\n",
+            variants_count(listed.iter().copied(), "usize")
+        ));
+        listed.push(variant);
+    }
+
+    let len = variants_count(listed, "usize");
+
+    format!(
+        "
+        impl<T> std::ops::Index<{enum_ident}> for [T; {len}]
+        {{
+            type Output = T;
+
+            #[inline]
+            fn index(&self, index: {enum_ident}) -> &Self::Output
+            {{
+                &self[match index
+                {{
+                    {index_match}
+                }}]
+            }}
+        }}
+
+        impl<T> std::ops::IndexMut<{enum_ident}> for [T; {len}]
+        {{
+            #[inline]
+            fn index_mut(&mut self, index: {enum_ident}) -> &mut Self::Output
+            {{
+                &mut self[match index
+                {{
+                    {index_match}
+                }}]
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `<enum name>Set` bitset, with the same visibility as the enum unless specified by
+/// `#[enum_vis(...)]`, representing a set of variants. The bits are stored in a `u32`, `u64`, or
+/// `u128` depending on the amount of variants.
+/// Variants annotated with `#[enum_skip]` cannot be stored in the set, using them as argument of
+/// the set methods causes a panic.
+/// # Panics
+/// Panics if `input` does not belong to an enum, the enum has generic parameters, or it has more
+/// than 128 variants.
+#[proc_macro_derive(EnumBitSet, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[must_use]
+pub fn enum_bit_set(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    enum_data.assert_not_generic("EnumBitSet");
+    let visibility = enum_data.type_visibility();
+
+    let Enum {
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let enum_ident = enum_ident.to_string();
+    let set_ident = format!("{}Set", unraw(&enum_ident));
+    let mut position_match = String::new();
+    let mut variant_match = String::new();
+    let mut listed = Vec::new();
+
+    for variant in &variants
+    {
+        let cfg = variant.cfg_attribute();
+        let pattern = variant.pattern(&enum_ident);
+
+        if variant.skipped()
+        {
+            position_match.push_str(&format!(
+                "{cfg}{pattern} => panic!(\"Variant {} cannot be stored in {set_ident}.\"),\n",
+                variant.ident
+            ));
+            continue;
+        }
+
+        let position = variants_count(listed.iter().copied(), "u32");
+        position_match.push_str(&format!("{cfg}{pattern} => {position},\n"));
+        variant_match.push_str(&format!(
+            "{cfg}{} => {},\n",
+            expr_pattern("position", &position),
+            variant.constructor(&enum_ident)
+        ));
+        listed.push(variant);
+    }
+
+    let bits = match listed.len()
+    {
+        0..=32 => "u32",
+        33..=64 => "u64",
+        65..=128 => "u128",
+        _ => panic!("EnumBitSet supports up to 128 variants.")
+    };
+    let len = variants_count(listed, "u32");
+    let bit = if variants.is_empty()
+    {
+        "match value {}".to_string()
+    }
+    else
+    {
+        format!("1 << match value {{ {position_match} }}")
+    };
+
+    let constness = constness(&variants);
+
+    let mut set = format!(
+        "
+        /// A set of [`{enum_ident}`] variants.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        {visibility}struct {set_ident}({bits});
+
+        impl {set_ident}
+        {{
+            /// The amount of storable variants.
+            const LEN: u32 = {len};
+
+            /// The bits of all the storable variants.
+            const ALL_BITS: {bits} = if Self::LEN == 0
+            {{
+                0
+            }}
+            else
+            {{
+                {bits}::MAX >> ({bits}::BITS - Self::LEN)
+            }};
+
+            /// Returns the bit associated with `value`.
+            #[inline]
+            #[must_use]
+            {constness}fn bit(value: {enum_ident}) -> {bits}
+            {{
+                {bit}
+            }}
+
+            /// Returns the variant associated with the bit at `position`.
+            #[inline]
+            #[must_use]
+            fn variant(position: u32) -> {enum_ident}
+            {{
+                match position
+                {{
+                    {variant_match}
+                    _ => unreachable!()
+                }}
+            }}
+
+            /// Returns an empty set.
+            #[inline]
+            #[must_use]
+            pub const fn new() -> Self {{ Self(0) }}
+
+            /// Returns a set containing all the variants.
+            #[inline]
+            #[must_use]
+            pub const fn all() -> Self {{ Self(Self::ALL_BITS) }}
+
+            /// Inserts `value` in the set. Returns whether it was not already contained.
+            #[inline]
+            pub {constness}fn insert(&mut self, value: {enum_ident}) -> bool
+            {{
+                let bit = Self::bit(value);
+                let inserted = self.0 & bit == 0;
+                self.0 |= bit;
+                inserted
+            }}
+
+            /// Removes `value` from the set. Returns whether it was contained.
+            #[inline]
+            pub {constness}fn remove(&mut self, value: {enum_ident}) -> bool
+            {{
+                let bit = Self::bit(value);
+                let removed = self.0 & bit != 0;
+                self.0 &= !bit;
+                removed
+            }}
+
+            /// Whether the set contains `value`.
+            #[inline]
+            #[must_use]
+            pub {constness}fn contains(&self, value: {enum_ident}) -> bool
+            {{
+                self.0 & Self::bit(value) != 0
+            }}
+
+            /// The amount of variants in the set.
+            #[inline]
+            #[must_use]
+            pub const fn len(&self) -> usize {{ self.0.count_ones() as usize }}
+
+            /// Whether the set is empty.
+            #[inline]
+            #[must_use]
+            pub const fn is_empty(&self) -> bool {{ self.0 == 0 }}
+
+            /// Removes all the variants from the set.
+            #[inline]
+            pub const fn clear(&mut self) {{ self.0 = 0; }}
+
+            /// Returns the set of the variants contained in `self` or `other`.
+            #[inline]
+            #[must_use]
+            pub const fn union(self, other: Self) -> Self {{ Self(self.0 | other.0) }}
+
+            /// Returns the set of the variants contained in both `self` and `other`.
+            #[inline]
+            #[must_use]
+            pub const fn intersection(self, other: Self) -> Self {{ Self(self.0 & other.0) }}
+
+            /// Returns the set of the variants contained in `self` but not in `other`.
+            #[inline]
+            #[must_use]
+            pub const fn difference(self, other: Self) -> Self {{ Self(self.0 & !other.0) }}
+
+            /// Returns the set of the variants contained in either `self` or `other`, but not \
+         both.
+            #[inline]
+            #[must_use]
+            pub const fn symmetric_difference(self, other: Self) -> Self
+            {{
+                Self(self.0 ^ other.0)
+            }}
+
+            /// Whether all the variants of `self` are contained in `other`.
+            #[inline]
+            #[must_use]
+            pub const fn is_subset(self, other: Self) -> bool {{ self.0 & !other.0 == 0 }}
+
+            /// Returns an iterator to the variants contained in the set, in declaration order.
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = {enum_ident}>
+            {{
+                let bits = self.0;
+                (0..Self::LEN).filter(move |i| bits & (1 << i) != 0).map(Self::variant)
+            }}
+        }}
+
+        impl FromIterator<{enum_ident}> for {set_ident}
+        {{
+            #[inline]
+            fn from_iter<I: IntoIterator<Item = {enum_ident}>>(iter: I) -> Self
+            {{
+                let mut set = Self::new();
+                set.extend(iter);
+                set
+            }}
+        }}
+
+        impl Extend<{enum_ident}> for {set_ident}
+        {{
+            #[inline]
+            fn extend<I: IntoIterator<Item = {enum_ident}>>(&mut self, iter: I)
+            {{
+                for value in iter
+                {{
+                    self.insert(value);
+                }}
+            }}
+        }}
+        "
+    );
+
+    for (trait_ident, func, op) in [
+        ("BitOr", "bitor", "union"),
+        ("BitAnd", "bitand", "intersection"),
+        ("BitXor", "bitxor", "symmetric_difference"),
+        ("Sub", "sub", "difference")
+    ]
+    {
+        set.push_str(&format!(
+            "
+            impl std::ops::{trait_ident} for {set_ident}
+            {{
+                type Output = Self;
+
+                #[inline]
+                fn {func}(self, rhs: Self) -> Self::Output {{ self.{op}(rhs) }}
+            }}
+
+            impl std::ops::{trait_ident}Assign for {set_ident}
+            {{
+                #[inline]
+                fn {func}_assign(&mut self, rhs: Self) {{ *self = self.{op}(rhs); }}
+            }}
+            "
+        ));
+    }
+
+    set.parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `FromStr` for a plain enum, matching the names of the variants, and a
+/// `from_str_ignore_case` method which ignores the ASCII case. The parse error is a generated
+/// `<enum name>FromStrError` struct, with the same visibility as the enum, holding the string that
+/// does not match any variant.
+/// If the enum is annotated with `#[enum_from_str(snake_case)]` the snake_case versions of the
+/// names, such as `config_file_key()` ones, are accepted as well.
+/// Variants annotated with `#[enum_skip]` cannot be parsed.
+/// # Panics
+/// Panics if `input` does not belong to an enum or `enum_from_str` contains unknown options.
+#[proc_macro_derive(
+    EnumFromStr,
+    attributes(enum_from_str, enum_default_payload, enum_skip, enum_vis)
+)]
+#[must_use]
+pub fn enum_from_str(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input).without_skipped();
+    let snake_case_aliases = enum_data.attribute("enum_from_str").is_some_and(|stream| {
+        for item in stream
+        {
+            match item.to_string().as_str()
+            {
+                "snake_case" => (),
+                item => panic!("Unknown enum_from_str option {item}.")
+            }
+        }
+
+        true
+    });
+    let from_str_header = enum_data.impl_header("std::str::FromStr");
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+
+    let Enum {
+        visibility,
+        ident: enum_ident,
+        variants,
+        ..
+    } = enum_data;
+    let enum_name = unraw(&enum_ident.to_string()).to_string();
+    let error_ident = format!("{enum_name}FromStrError");
+    let mut from_str_match = String::new();
+    let mut ignore_case_checks = String::new();
+
+    for variant in variants
+    {
+        let cfg = variant.cfg_attribute();
+        let constructor = variant.constructor("Self");
+        let mut names = vec![unraw(&variant.ident.to_string()).to_string()];
+
+        if snake_case_aliases
+        {
+            let alias = snake_case(&names[0]);
+
+            if alias != names[0]
+            {
+                names.push(alias);
+            }
+        }
+
+        for name in names
+        {
+            from_str_match.push_str(&format!("{cfg}\"{name}\" => Ok({constructor}),\n"));
+            ignore_case_checks.push_str(&format!(
+                "{cfg}if value.eq_ignore_ascii_case(\"{name}\") {{ return Ok({constructor}); }}\n"
+            ));
+        }
+    }
+
+    format!(
+        "
+        /// The error returned when a string does not match any [`{enum_ident}`] variant.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        {visibility}struct {error_ident}(pub String);
+
+        impl std::fmt::Display for {error_ident}
+        {{
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+            {{
+                write!(f, \"{{:?}} is not a valid {enum_name}\", self.0)
+            }}
+        }}
+
+        impl std::error::Error for {error_ident} {{}}
+
+        {from_str_header}
+        {{
+            type Err = {error_ident};
+
+            #[inline]
+            fn from_str(value: &str) -> Result<Self, Self::Err>
+            {{
+                match value
+                {{
+                    {from_str_match}
+                    _ => Err({error_ident}(value.to_string()))
+                }}
+            }}
+        }}
+
+        {impl_header}
+        {{
+            /// Parses `value` like `FromStr` does, but ignoring the ASCII case.
+            /// # Errors
+            /// Returns an error if `value` does not match any variant.
+            #[inline]
+            {vis}fn from_str_ignore_case(value: &str) -> Result<Self, {error_ident}>
+            {{
+                {ignore_case_checks}
+                Err({error_ident}(value.to_string()))
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements the `config_file_key()` method, returning the snake_case version of the name of the
+/// variant, and its inverse `from_config_file_key()`, using the same convention of the keys of the
+/// colors and binds stored in the config file.
+/// Variants annotated with `#[enum_skip]` are not returned by `from_config_file_key()`.
+#[proc_macro_derive(EnumConfigKey, attributes(enum_default_payload, enum_skip, enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_config_key(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let variants = enum_data.variants;
+    let mut key_match = String::new();
+    let mut from_key_match = String::new();
+
+    for variant in &variants
+    {
+        let cfg = variant.cfg_attribute();
+        let key = snake_case(&variant.ident.to_string());
+        key_match.push_str(&format!("{cfg}{} => \"{key}\",\n", variant.pattern("Self")));
+
+        if !variant.skipped()
+        {
+            from_key_match
+                .push_str(&format!("{cfg}\"{key}\" => Some({}),\n", variant.constructor("Self")));
+        }
+    }
+
+    let constness = constness(&variants);
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns the string key used in the config file associated with the variant.
+            #[inline]
+            #[must_use]
+            {vis}{constness}fn config_file_key(self) -> &'static str
+            {{
+                match self
+                {{
+                    {key_match}
+                }}
+            }}
+
+            /// Returns the variant associated with the config file key `key`, if any.
+            #[inline]
+            #[must_use]
+            {vis}fn from_config_file_key(key: &str) -> Option<Self>
+            {{
+                match key
+                {{
+                    {from_key_match}
+                    _ => None
+                }}
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements the `label()` method, returning the name of the variant with its words separated by
+/// spaces to be shown in UI elements, such as "Free Draw" for `FreeDraw`.
+#[proc_macro_derive(EnumLabel, attributes(enum_vis))]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_label(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("");
+    let vis = enum_data.item_visibility();
+    let variants = enum_data.variants;
+    let constness = constness(&variants);
+    let mut label_match = String::new();
+
+    for variant in &variants
+    {
+        label_match.push_str(&format!(
+            "{}{} => \"{}\",\n",
+            variant.cfg_attribute(),
+            variant.pattern("Self"),
+            label(&variant.ident.to_string())
+        ));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            /// Returns the text representing the variant in UI elements.
+            #[inline]
+            #[must_use]
+            {vis}{constness}fn label(self) -> &'static str
+            {{
+                match self
+                {{
+                    {label_match}
+                }}
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Implements `Display` for a plain enum, writing the names of the variants with their words
+/// separated by spaces, such as `FreeDraw` displayed as "Free Draw".
+#[proc_macro_derive(EnumDisplay)]
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn enum_display(input: TokenStream) -> TokenStream
+{
+    let enum_data = Enum::new(input);
+    let impl_header = enum_data.impl_header("std::fmt::Display");
+    let mut display_match = String::new();
+
+    for variant in enum_data.variants
+    {
+        display_match.push_str(&format!(
+            "{}{} => \"{}\",\n",
+            variant.cfg_attribute(),
+            variant.pattern("Self"),
+            label(&variant.ident.to_string())
+        ));
+    }
+
+    format!(
+        "
+        {impl_header}
+        {{
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+            {{
+                f.write_str(match self
+                {{
+                    {display_match}
+                }})
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates an enum containing a subset of the variants of a plain enum, plus the conversions
+/// `From<subset>` for the parent enum and `TryFrom<parent>` for the subset enum, whose error
+/// returns the parent value which has no corresponding subset variant.
+/// The subset enum may be preceded by attributes and a visibility, and derives `Clone`, `Copy`,
+/// `Debug`, `PartialEq`, and `Eq`.
+/// # Examples
+/// ```ignore
+/// enum_subset!(Tool => pub(crate) DrawTool { Square, Triangle, Circle });
+/// ```
+/// # Panics
+/// Panics if `stream` is not formatted as in the example or a variant of the subset has fields.
+#[proc_macro]
+pub fn enum_subset(stream: TokenStream) -> TokenStream
+{
+    let mut iter = stream.into_iter();
+    let mut parent = String::new();
+
+    loop
+    {
+        match iter.next_value()
+        {
+            TokenTree::Punct(p) if p.as_char() == '=' =>
+            {
+                assert!(
+                    match_or_panic!(iter.next_value(), TokenTree::Punct(p), p).as_char() == '>',
+                    "Expected => after the parent enum."
+                );
+                break;
+            },
+            item => parent.push_str(&item.to_string())
+        };
+    }
+
+    assert!(!parent.is_empty(), "Missing parent enum.");
+
+    let mut attributes = String::new();
+    let mut visibility = String::new();
+
+    let subset = loop
+    {
+        match iter.next_value()
+        {
+            TokenTree::Punct(p) if p.as_char() == '#' =>
+            {
+                attributes.push_str(&format!("#{}\n", iter.next_value()));
+            },
+            TokenTree::Ident(ident) if visibility.is_empty() && &ident.to_string() == "pub" =>
+            {
+                visibility.push_str("pub ");
+            },
+            TokenTree::Group(group)
+                if !visibility.is_empty() && group.delimiter() == Delimiter::Parenthesis =>
+            {
+                visibility.insert_str(visibility.len() - 1, &group.to_string());
+            },
+            TokenTree::Ident(ident) => break ident,
+            item => panic!("Unexpected token {item}.")
+        };
+    };
+
+    let variants = enum_variants(iter.next_value());
+    assert!(iter.next().is_none(), "Unexpected tokens after the subset variants.");
+
+    let mut body = String::new();
+    let mut from_match = String::new();
+    let mut try_from_match = String::new();
+
+    for variant in &variants
+    {
+        let ident = &variant.ident;
+        assert!(
+            matches!(variant.fields, Fields::Unit),
+            "Variant {ident} has fields, enum_subset requires unit variants."
+        );
+
+        for attribute in &variant.attributes
+        {
+            body.push_str(&format!("#{attribute} "));
+        }
+
+        body.push_str(&format!("{ident},\n"));
+
+        let cfg = variant.cfg_attribute();
+        from_match.push_str(&format!("{cfg}{subset}::{ident} => Self::{ident},\n"));
+        try_from_match.push_str(&format!("{cfg}{parent}::{ident} => Ok(Self::{ident}),\n"));
+    }
+
+    format!(
+        "
+        {attributes}
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        {visibility}enum {subset}
+        {{
+            {body}
+        }}
+
+        impl From<{subset}> for {parent}
+        {{
+            #[inline]
+            fn from(value: {subset}) -> Self
+            {{
+                match value
+                {{
+                    {from_match}
+                }}
+            }}
+        }}
+
+        impl TryFrom<{parent}> for {subset}
+        {{
+            type Error = {parent};
+
+            #[inline]
+            #[allow(unreachable_patterns)]
+            fn try_from(value: {parent}) -> Result<Self, Self::Error>
+            {{
+                match value
+                {{
+                    {try_from_match}
+                    value => Err(value)
+                }}
+            }}
+        }}
+        "
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates an enum whose variants are the CamelCase versions of a list of strings, plus a
+/// constant array containing the strings, named after the enum in SCREAMING_SNAKE_CASE followed by
+/// `_STRS`, and an `as_str` method returning the string associated with a variant.
+/// The enum may be preceded by attributes and followed by a visibility, and derives `Clone`,
+/// `Copy`, `Debug`, `PartialEq`, and `Eq`.
+/// # Examples
+/// ```ignore
+/// named_enum!(Category: pub, ["wood", "stone", "dark metal"]);
+/// // Equivalent to
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// pub enum Category
+/// {
+///     Wood,
+///     Stone,
+///     DarkMetal
+/// }
+///
+/// pub const CATEGORY_STRS: [&'static str; 3] = ["wood", "stone", "dark metal"];
+/// ```
+/// Compilation fails if two strings are converted to the same variant, such as `"a b"` and
+/// `"a_b"`.
+/// # Panics
+/// Panics if `stream` is not formatted as in the example or a string cannot be converted to a
+/// variant identifier.
+#[proc_macro]
+pub fn named_enum(stream: TokenStream) -> TokenStream
+{
+    let mut segments = split_at_commas(stream).into_iter();
+    let mut attributes = String::new();
+    let mut visibility = String::new();
+    let mut ident = None;
+    let mut iter = segments.next_value().into_iter();
+
+    while let Some(item) = iter.next()
+    {
+        match item
+        {
+            TokenTree::Punct(p) if p.as_char() == '#' && ident.is_none() =>
+            {
+                attributes.push_str(&format!("#{}\n", iter.next_value()));
+            },
+            TokenTree::Ident(i) if ident.is_none() => ident = i.to_string().into(),
+            TokenTree::Punct(p) if p.as_char() == ':' && ident.is_some() =>
+            {
+                visibility = iter.by_ref().collect::<TokenStream>().to_string();
+            },
+            item => panic!("Unexpected token {item}.")
+        };
+    }
+
+    let ident = ident.expect("Missing enum name.");
+    let strings = match_or_panic!(segments.next_value().as_slice(), [TokenTree::Group(group)], {
+        assert!(group.delimiter() == Delimiter::Bracket, "Expected a list of strings.");
+        split_at_commas(group.stream())
+    });
+    assert!(segments.next().is_none(), "Unexpected tokens after the strings.");
+
+    let array = format!("{}_STRS", snake_case(&ident).to_ascii_uppercase());
+    let mut body = String::new();
+    let mut values = String::new();
+    let mut as_str_match = String::new();
+    let mut variants = Vec::<(String, String)>::new();
+
+    for (i, string) in strings.iter().enumerate()
+    {
+        let string = match_or_panic!(string.as_slice(), [TokenTree::Literal(lit)], lit.to_string());
+        assert!(string.starts_with('"'), "{string} is not a string.");
+
+        let value = string.trim_matches('"');
+        let variant = camel_case(value);
+
+        if let Some((_, other)) = variants.iter().find(|(v, _)| *v == variant)
+        {
+            return format!(
+                "compile_error!(\"The strings \\\"{other}\\\" and \\\"{value}\\\" are both \
+                 converted to the variant {variant}.\");"
+            )
+            .parse()
+            .unwrap();
+        }
+
+        variants.push((variant.clone(), value.to_string()));
+        body.push_str(&format!("{variant},\n"));
+        values.push_str(&format!("{string}, "));
+        as_str_match.push_str(&format!("Self::{variant} => {array}[{i}],\n"));
+    }
+
+    format!(
+        "
+        {attributes}
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        {visibility} enum {ident}
+        {{
+            {body}
+        }}
+
+        {visibility} const {array}: [&'static str; {}] = [{values}];
+
+        impl {ident}
+        {{
+            /// Returns the string associated with `self`.
+            #[inline]
+            #[must_use]
+            {visibility} const fn as_str(self) -> &'static str
+            {{
+                match self
+                {{
+                    {as_str_match}
+                }}
+            }}
+        }}
+        ",
+        strings.len()
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Returns the visibility and the name of the array described by `segment`.
+/// # Panics
+/// Panics if `segment` does not end with an identifier.
+#[inline]
+#[must_use]
+fn str_array_ident(segment: &[TokenTree]) -> (String, String)
+{
+    match_or_panic!(
+        segment,
+        [visibility @ .., TokenTree::Ident(ident)],
+        (
+            visibility.iter().cloned().collect::<TokenStream>().to_string(),
+            ident.to_string()
+        )
+    )
+}
+
+//=======================================================================//
+
+/// Returns the indexes of the elements of a `str_array`, described by `segment` as either a count
+/// or a range, separated by `step`. A count is the amount of indexes, a range their bounds.
+/// # Panics
+/// Panics if `segment` is neither an integer nor a non empty integer range.
+fn str_array_indexes(
+    segment: &[TokenTree],
+    step: usize
+) -> std::iter::StepBy<std::ops::Range<usize>>
+{
+    let value = segment
+        .iter()
+        .cloned()
+        .collect::<TokenStream>()
+        .to_string()
+        .replace([' ', '_'], "");
+    let parse = |value: &str| {
+        value
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("Invalid str_array index {value}."))
+    };
+
+    let range = if let Some((start, end)) = value.split_once("..=")
+    {
+        parse(start)..parse(end).checked_add(1).expect("Too many str_array indexes.")
+    }
+    else if let Some((start, end)) = value.split_once("..")
+    {
+        parse(start)..parse(end)
+    }
+    else
+    {
+        return (0..parse(&value).checked_mul(step).expect("Too many str_array indexes."))
+            .step_by(step);
+    };
+
+    assert!(!range.is_empty(), "Empty str_array range {value}.");
+    range.step_by(step)
+}
+
+//=======================================================================//
+
+/// Returns the alphabetical representation of `index`, so that 0 is `a`, 25 is `z`, 26 is `aa`,
+/// and so on.
+#[inline]
+#[must_use]
+fn alphabetic_index(mut index: usize) -> String
+{
+    let mut value = Vec::new();
+
+    loop
+    {
+        value.push(b'a' + u8::try_from(index % 26).unwrap());
+
+        if index < 26
+        {
+            break;
+        }
+
+        index = index / 26 - 1;
+    }
+
+    value.reverse();
+    String::from_utf8(value).unwrap()
+}
+
+//=======================================================================//
+
+/// Generates an array of static [`str`] with name, size, prefix, and suffix defined in `stream`.
+/// The size can also be a `start..end` or `start..=end` range of indexes, and the numbers may
+/// contain `_` separators. The prefix and the suffix are optional. The name can be preceded by a
+/// visibility, such as `pub(crate) ARRAY`.
+/// Instead of the prefix and the suffix a string template can be specified, in which every `{i}`
+/// is replaced by the index. The arguments can also be specified by name, as `name = ARRAY`,
+/// `count = 4`, `prefix = i_`, `suffix = _px`, and `template = "i_{i}"`, after the positional
+/// ones.
+/// # Examples
+/// ```ignore
+/// str_array(ARRAY, 4, i_);
+/// // Equivalent to
+/// const ARRAY: [&'static str; 4] = ["i_0", "i_1", "i_2", "i_3"];
+///
+/// str_array(ARRAY, 2, i_, _px);
+/// // Equivalent to
+/// const ARRAY: [&'static str; 2] = ["i_0_px", "i_1_px"];
+///
+/// str_array(IDS, 8..11, slot_);
+/// // Equivalent to
+/// const IDS: [&'static str; 3] = ["slot_8", "slot_9", "slot_10"];
+///
+/// str_array(NAMES, 2, "layer_{i}_overlay");
+/// // Equivalent to
+/// const NAMES: [&'static str; 2] = ["layer_0_overlay", "layer_1_overlay"];
+///
+/// str_array(name = ARRAY, count = 2, prefix = i_, suffix = _px,);
+/// // Equivalent to
+/// const ARRAY: [&'static str; 2] = ["i_0_px", "i_1_px"];
+/// ```
+/// The positional arguments can be followed by these named options:
+/// - `pad = width`, pads the indexes with zeros up to `width` digits.
+/// ```ignore
+/// str_array(FRAMES, 120, frame_, pad = 3);
+/// // Equivalent to
+/// const FRAMES: [&'static str; 120] = ["frame_000", "frame_001", ..., "frame_119"];
+/// ```
+/// - `step = n`, separates the indexes by `n`. With a count the array still contains count
+///   elements, with a range the indexes do not exceed its bounds.
+/// ```ignore
+/// str_array(EVEN, 3, i_, step = 2);
+/// // Equivalent to
+/// const EVEN: [&'static str; 3] = ["i_0", "i_2", "i_4"];
+/// ```
+/// - `columns = n`, generates a two dimensional array whose rows are described by the count or
+///   range, each one containing `n` elements. The row and column indexes are separated by `_`, or
+///   replace `{row}` and `{col}` in the template.
+/// ```ignore
+/// str_array(CELLS, 2, "cell_{row}_{col}", columns = 2);
+/// // Equivalent to
+/// const CELLS: [[&'static str; 2]; 2] = [["cell_0_0", "cell_0_1"], ["cell_1_0", "cell_1_1"]];
+/// ```
+/// - `bytes = true`, generates byte string literals, whose type is `&'static [u8]` unless `ty` is
+///   specified.
+/// ```ignore
+/// str_array(KEYS, 2, k_, bytes = true);
+/// // Equivalent to
+/// const KEYS: [&'static [u8]; 2] = [b"k_0", b"k_1"];
+/// ```
+/// - `alpha = true`, replaces the indexes with lowercase letters, so that 0 is `a`, 25 is `z`, 26
+///   is `aa`, and so on.
+/// ```ignore
+/// str_array(GROUPS, 28, group_, alpha = true);
+/// // Equivalent to
+/// const GROUPS: [&'static str; 28] = ["group_a", "group_b", ..., "group_aa", "group_ab"];
+/// ```
+/// - `hex = true`, formats the indexes as lowercase hexadecimal numbers.
+/// ```ignore
+/// str_array(SLOTS, 9..12, id_, hex = true, pad = 2);
+/// // Equivalent to
+/// const SLOTS: [&'static str; 3] = ["id_09", "id_0a", "id_0b"];
+/// ```
+/// - `storage = static`, generates a `static` instead of a `const`.
+/// - `ty = Type`, the type of the elements. If it is not a reference each string is wrapped in
+///   `Type(...)`, so that it can be a newtype.
+/// ```ignore
+/// str_array(IDS, 2, id_, storage = static, ty = Id);
+/// // Equivalent to
+/// static IDS: [Id; 2] = [Id("id_0"), Id("id_1")];
+/// ```
+/// # Panics
+/// Panics if `input` is not properly formatted.
+#[proc_macro]
+pub fn str_array(input: TokenStream) -> TokenStream
+{
+    let mut positional = Vec::new();
+    let mut name = None;
+    let mut indexes = None;
+    let mut prefix = None;
+    let mut suffix = None;
+    let mut template = None;
+    let mut options = false;
+    let mut pad = 0;
+    let mut step = 1;
+    let mut columns = None;
+    let mut storage = "const".to_string();
+    let mut ty = None;
+    let mut bytes = false;
+    let mut alpha = false;
+    let mut hex = false;
+
+    for segment in split_at_commas(input)
+    {
+        match segment.as_slice()
+        {
+            [TokenTree::Ident(key), TokenTree::Punct(p), tokens @ ..] if p.as_char() == '=' =>
+            {
+                let value = tokens.iter().cloned().collect::<TokenStream>().to_string();
+                options = true;
+
+                match key.to_string().as_str()
+                {
+                    "name" => name = str_array_ident(tokens).into(),
+                    "count" => indexes = tokens.to_vec().into(),
+                    "prefix" => prefix = value.trim_matches('"').to_string().into(),
+                    "suffix" => suffix = value.trim_matches('"').to_string().into(),
+                    "template" => template = value.into(),
+                    "pad" => pad = value.parse::<usize>().unwrap(),
+                    "step" =>
+                    {
+                        step = value.parse::<usize>().unwrap();
+                        assert!(step != 0, "The step cannot be 0.");
+                    },
+                    "columns" => columns = value.parse::<usize>().unwrap().into(),
+                    "storage" =>
+                    {
+                        assert!(
+                            value == "const" || value == "static",
+                            "The storage must be either const or static."
+                        );
+                        storage = value;
+                    },
+                    "ty" => ty = value.into(),
+                    "bytes" => bytes = value.parse::<bool>().unwrap(),
+                    "alpha" => alpha = value.parse::<bool>().unwrap(),
+                    "hex" => hex = value.parse::<bool>().unwrap(),
+                    key => panic!("Unknown str_array option {key}.")
+                };
+            },
+            _ =>
+            {
+                assert!(!options, "The positional arguments must precede the named ones.");
+                positional.push(segment);
+            }
+        };
+    }
+
+    assert!(positional.len() <= 4, "Too many arguments.");
+    let mut positional = positional.into_iter();
+
+    if let Some(segment) = positional.next()
+    {
+        assert!(name.is_none(), "The name is specified twice.");
+        name = str_array_ident(&segment).into();
+    }
+
+    if let Some(segment) = positional.next()
+    {
+        assert!(indexes.is_none(), "The count is specified twice.");
+        indexes = segment.into();
+    }
+
+    for (i, segment) in positional.enumerate()
+    {
+        let affix = match segment.as_slice()
+        {
+            [affix] => affix.to_string(),
+            _ => panic!("The prefix and the suffix must be single tokens.")
+        };
+
+        if i == 0 && affix.starts_with('"')
+        {
+            assert!(template.is_none(), "The template is specified twice.");
+            template = affix.into();
+            continue;
+        }
+
+        let affix_slot = if i == 0 { &mut prefix } else { &mut suffix };
+        assert!(affix_slot.is_none(), "The prefix or the suffix is specified twice.");
+        *affix_slot = affix.into();
+    }
+
+    let (visibility, ident) = name.expect("Missing str_array name.");
+    let indexes = indexes.expect("Missing str_array count.");
+    let prefix = prefix.unwrap_or_default();
+    let suffix = suffix.unwrap_or_default();
+    let template = template.map(|template| {
+        assert!(
+            prefix.is_empty() && suffix.is_empty(),
+            "A template cannot be combined with a prefix or a suffix."
+        );
+        let template = template[1..template.len() - 1].to_string();
+
+        if columns.is_some()
+        {
+            assert!(
+                template.contains("{row}") || template.contains("{col}"),
+                "The template contains neither {{row}} nor {{col}}."
+            );
+        }
+        else
+        {
+            assert!(template.contains("{i}"), "The template does not contain {{i}}.");
+        }
+
+        template
+    });
+
+    let indexes = str_array_indexes(&indexes, step);
+    let ty = ty.unwrap_or_else(|| if bytes { "&'static [u8]" } else { "&'static str" }.to_string());
+    let literal = if bytes { "b" } else { "" };
+    let newtype = !ty.starts_with('&');
+    let element_len = template.as_ref().map_or(prefix.len() + suffix.len(), String::len) +
+        indexes
+            .clone()
+            .next_back()
+            .map_or(0, |i| i.to_string().len())
+            .max(pad) +
+        if newtype { ty.len() + 6 } else { 4 };
+    let mut result =
+        String::with_capacity(128 + indexes.len() * columns.unwrap_or(1) * element_len * 2);
+
+    assert!(!alpha || pad == 0, "Alphabetical indexes cannot be padded.");
+    assert!(!(alpha && hex), "The indexes cannot be both alphabetical and hexadecimal.");
+    let index = |i: usize| {
+        if alpha
+        {
+            alphabetic_index(i)
+        }
+        else if hex
+        {
+            format!("{i:0pad$x}")
+        }
+        else
+        {
+            format!("{i:0pad$}")
+        }
+    };
+    let element = |row: Option<usize>, i: usize| {
+        let value = match (&template, row)
+        {
+            (Some(template), None) => template.replace("{i}", &index(i)),
+            (Some(template), Some(row)) =>
+            {
+                template.replace("{row}", &index(row)).replace("{col}", &index(i))
+            },
+            (None, None) => format!("{prefix}{}{suffix}", index(i)),
+            (None, Some(row)) => format!("{prefix}{}_{}{suffix}", index(row), index(i))
+        };
+
+        if newtype
+        {
+            format!("{ty}({literal}\"{value}\"), ")
+        }
+        else
+        {
+            format!("{literal}\"{value}\", ")
+        }
+    };
+
+    match columns
+    {
+        None =>
+        {
+            result.push_str(&format!(
+                "{visibility} {storage} {ident}: [{ty}; {}] = [",
+                indexes.len()
+            ));
+
+            for i in indexes
+            {
+                result.push_str(&element(None, i));
+            }
+        },
+        Some(columns) =>
+        {
+            result.push_str(&format!(
+                "{visibility} {storage} {ident}: [[{ty}; {columns}]; {}] = [",
+                indexes.len()
+            ));
+
+            for row in indexes
+            {
+                result.push('[');
+
+                for col in 0..columns
+                {
+                    result.push_str(&element(Some(row), col));
+                }
+
+                result.push_str("], ");
+            }
+        }
+    };
+
+    result.push_str("];");
+    result.parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Generates an array of static [`str`] containing the lines of a text file, whose path is
+/// relative to the directory of the crate's manifest, verbatim. The name can be preceded by a
+/// visibility.
+/// The following options can follow the path:
+/// - `trim = true`, trims the whitespace surrounding the lines.
+/// - `skip_empty = true`, skips the empty lines, after the trimming if any.
+/// # Examples
+/// ```ignore
+/// // categories.txt
+/// // wood
+/// //
+/// //   stone
+/// str_array_from_file(pub CATEGORIES, "data/categories.txt", trim = true, skip_empty = true);
+/// // Equivalent to
+/// pub const CATEGORIES: [&'static str; 2] = ["wood", "stone"];
+/// ```
+/// # Panics
+/// Panics if `input` is not properly formatted or the file cannot be read.
+#[proc_macro]
+pub fn str_array_from_file(input: TokenStream) -> TokenStream
+{
+    let mut segments = split_at_commas(input).into_iter();
+    let (visibility, ident) = str_array_ident(&segments.next_value());
+    let file = match_or_panic!(
+        segments.next_value().as_slice(),
+        [TokenTree::Literal(file)],
+        file.to_string()
+    );
+    let mut trim = false;
+    let mut skip_empty = false;
+
+    for segment in segments
+    {
+        let [TokenTree::Ident(key), TokenTree::Punct(p), value] = segment.as_slice()
+        else
+        {
+            panic!("Expected a key = value option.");
+        };
+        assert!(p.as_char() == '=', "Expected = after {key}.");
+        let value = value.to_string();
+
+        match key.to_string().as_str()
+        {
+            "trim" => trim = value.parse::<bool>().unwrap(),
+            "skip_empty" => skip_empty = value.parse::<bool>().unwrap(),
+            key => panic!("Unknown str_array_from_file option {key}.")
+        };
+    }
+
+    let file = file.trim_matches('"');
+    let path = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join(file);
+    let lines = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Could not read {}.", path.display()));
+    let lines = lines
+        .lines()
+        .map(|line| {
+            if trim
+            {
+                line.trim()
+            }
+            else
+            {
+                line
+            }
+        })
+        .filter(|line| !skip_empty || !line.is_empty())
+        .collect::<Vec<_>>();
+
+    // Makes the crate rebuild when the file changes.
+    let mut result = format!("const _: &str = include_str!({:?});", path.to_str().unwrap());
+    result.push_str(&format!("{visibility} const {ident}: [&'static str; {}] = [", lines.len()));
+
+    for line in lines
+    {
+        result.push_str(&format!("{line:?}, "));
+    }
+
+    result.push_str("];");
+    result.parse().unwrap()
+}
+
+//=======================================================================//
+
+/// Returns the path of the docs directory and the named options, `key = value`, described by the
+/// arguments of a manual macro. The docs directory is an optional leading string relative to the
+/// directory of the crate's manifest, `docs` if not specified.
+/// # Panics
+/// Panics if `stream` is not properly formatted.
+#[inline]
+#[must_use]
+fn manual_arguments(stream: TokenStream) -> (std::path::PathBuf, Vec<(String, String)>)
+{
+    let mut docs = None;
+    let mut options = Vec::new();
+
+    for (i, segment) in split_at_commas(stream).into_iter().enumerate()
+    {
+        match segment.as_slice()
+        {
+            [TokenTree::Literal(lit)] if i == 0 =>
+            {
+                let lit = lit.to_string();
+                assert!(lit.starts_with('"'), "The docs directory must be a string.");
+                docs = lit.trim_matches('"').to_string().into();
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), value @ ..] if p.as_char() == '=' =>
+            {
+                options.push((
+                    key.to_string(),
+                    value.iter().cloned().collect::<TokenStream>().to_string()
+                ));
+            },
+            _ => panic!("Expected the docs directory or a key = value option.")
+        };
+    }
+
+    (
+        std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+            .join(docs.unwrap_or_else(|| "docs".to_string())),
+        options
+    )
+}
+
+//=======================================================================//
+
+/// Returns the path of the docs directory described by `stream`, the arguments of a manual macro
+/// without options.
+/// # Panics
+/// Panics if `stream` is not properly formatted.
+#[inline]
+#[must_use]
+fn docs_directory(stream: TokenStream) -> std::path::PathBuf
+{
+    let (docs, options) = manual_arguments(stream);
+    assert!(options.is_empty(), "Unexpected options after the docs directory.");
+    docs
+}
+
+//=======================================================================//
+
+/// Returns the sections order described by `value`, a list of strings such as
+/// `["general", "edit"]`.
+/// # Panics
+/// Panics if `value` is not a list of strings.
+#[inline]
+#[must_use]
+fn manual_order(value: &str) -> Vec<String>
+{
+    let list = value
+        .trim()
+        .strip_prefix('[')
+        .and_then(|list| list.strip_suffix(']'))
+        .expect("The manual order must be a list of strings.");
+
+    list.split(',')
+        .map(str::trim)
+        .filter(|section| !section.is_empty())
+        .map(|section| {
+            assert!(
+                section.len() > 1 && section.starts_with('"') && section.ends_with('"'),
+                "{section} is not a string."
+            );
+            section.trim_matches('"').to_string()
+        })
+        .collect()
+}
+
+//=======================================================================//
+
+/// Returns the path of the docs directory, the sections order, and whether the Ctrl key is to be
+/// mentioned as Cmd on macOS, described by `stream`, the arguments of a manual macro whose only
+/// options are `order` and `platform_keys`.
+/// # Panics
+/// Panics if `stream` is not properly formatted.
+#[inline]
+#[must_use]
+fn ordered_docs_directory(stream: TokenStream) -> (std::path::PathBuf, Option<Vec<String>>, bool)
+{
+    let (docs, options) = manual_arguments(stream);
+    let mut order = None;
+    let mut platform = false;
+
+    for (key, value) in options
+    {
+        match key.as_str()
+        {
+            "order" => order = manual_order(&value).into(),
+            "platform_keys" => platform = bool_option(&key, &value),
+            key => panic!("Unknown manual option {key}.")
+        };
+    }
+
+    (docs, order, platform)
+}
+
+//=======================================================================//
+
+/// Returns the names and the paths of the subdirectories of `docs` containing a localized manual,
+/// sorted alphabetically.
+/// # Panics
+/// Panics if `docs` cannot be read or contains no localized manual.
+#[inline]
+#[must_use]
+fn manual_locales(docs: &std::path::Path) -> Vec<(String, std::path::PathBuf)>
+{
+    let mut locales = std::fs::read_dir(docs)
+        .unwrap_or_else(|_| panic!("Could not read {}.", docs.display()))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("manual").is_dir())
+        .map(|path| (path.file_name().unwrap().to_str().unwrap().to_string(), path))
+        .collect::<Vec<_>>();
+    assert!(!locales.is_empty(), "No localized manual in {}.", docs.display());
+    locales.sort_unstable();
+    locales
+}
+
+//=======================================================================//
+
+/// Returns the items embedding the files of the manual and the subtool binds contained in `docs` in
+/// the generated code, so that the crate is rebuilt when they change.
+/// # Panics
+/// Panics if the docs directories cannot be read.
+#[inline]
+#[must_use]
+fn track_docs(docs: &std::path::Path) -> String
+{
+    #[inline]
+    fn track(dir: &std::path::Path, tracking: &mut String)
+    {
+        for entry in std::fs::read_dir(dir).unwrap()
+        {
+            let path = entry.unwrap().path();
+
+            if path.is_dir()
+            {
+                track(&path, tracking);
+            }
+            else
+            {
+                tracking.push_str(&format!(
+                    "const _: &str = include_str!({:?});\n",
+                    path.to_str().unwrap()
+                ));
+            }
+        }
+    }
+
+    let mut tracking = String::new();
+    track(&docs.join("manual"), &mut tracking);
+
+    let binds = docs.join("subtools binds");
+
+    if binds.exists()
+    {
+        track(&binds, &mut tracking);
+    }
+
+    let cfgs = docs.join("manual.cfg");
+
+    if cfgs.exists()
+    {
+        tracking
+            .push_str(&format!("const _: &str = include_str!({:?});\n", cfgs.to_str().unwrap()));
+    }
+
+    tracking
+}
+
+//=======================================================================//
+
+/// Returns the text of the manual file `file` without the markdown syntax and with the quotes
+/// escaped.
+#[inline]
+#[must_use]
+fn manual_text(file: &str) -> String
+{
+    #[inline]
+    #[must_use]
+    fn prose(text: &str) -> String
+    {
+        text.replace('\"', "\\\"").replace("   ", "").replace('`', "")
+    }
+
+    let file = file.trim();
+    let mut blocks = file.strip_prefix("### ").unwrap_or(file).split("```ini");
+    let mut text = prose(blocks.next_value());
+
+    for block in blocks
+    {
+        let (code, rest) = block.split_once("```").unwrap_or((block, ""));
+
+        text.push_str("```ini\n");
+        text.push_str(
+            &code
+                .trim()
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .replace('\"', "\\\"")
+        );
+        text.push_str("\n```");
+        text.push_str(&prose(rest));
+    }
+
+    text
+}
+
+//=======================================================================//
+
+/// Splits the manual explanation `exp` in the text preceding its first `###` subheading and the
+/// titles and texts of the subsections introduced by its `###` subheadings.
+#[inline]
+#[must_use]
+fn explanation_subsections(exp: &str) -> (&str, Vec<(&str, &str)>)
+{
+    let mut subsections = Vec::new();
+    let mut heading: Option<(&str, usize)> = None;
+    let mut main_end = exp.len();
+    let mut in_code = false;
+    let mut offset = 0;
+
+    for line in exp.split_inclusive('\n')
+    {
+        if line.starts_with("```")
+        {
+            in_code = !in_code;
+        }
+        else if let Some(title) = line.strip_prefix("### ").filter(|_| !in_code)
+        {
+            match heading
+            {
+                Some((title, start)) =>
+                {
+                    subsections.push((title, exp[start..offset].trim_end_matches(['\r', '\n'])));
+                },
+                None => main_end = offset
+            };
+
+            heading = (title.trim(), offset + line.len()).into();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((title, start)) = heading
+    {
+        subsections.push((title, &exp[start..]));
+    }
+
+    (exp[..main_end].trim_end_matches(['\r', '\n']), subsections)
+}
+
+//=======================================================================//
+
+/// Returns the explanation of the subtool `name` described by the processed manual text
+/// `processed`, with its binds, read from `docs`, following the first line.
+/// # Panics
+/// Panics if the binds file of the subtool cannot be read.
+#[inline]
+#[must_use]
+fn subtool_explanation(docs: &std::path::Path, name: &str, processed: &str) -> String
+{
+    let mut lines = processed.lines();
+    let mut exp = lines.next_value().to_string();
+    exp.push_str(" (");
+    exp.push_str(&std::fs::read_to_string(docs.join(format!("subtools binds/{name}.md"))).unwrap());
+    exp.push_str(")\n");
+
+    for line in lines
+    {
+        exp.push_str(line);
+        exp.push('\n');
+    }
+
+    exp.pop();
+    exp
+}
+
+//=======================================================================//
+
+/// Splits `text` in the pieces of plain text, the markdown links `[text](url)`, the markdown
+/// images `![alt](path)`, and the ```ini code blocks it contains.
+#[inline]
+#[must_use]
+fn markdown_spans(text: &str) -> Vec<MarkdownSpan<'_>>
+{
+    let mut spans = Vec::new();
+    let mut rest = text;
+
+    while let Some((before, block)) = rest.split_once("```ini\n")
+    {
+        let (code, after) = block.split_once("\n```").unwrap_or((block, ""));
+        inline_spans(before.trim_end_matches(['\r', '\n']), &mut spans);
+        spans.push(MarkdownSpan::Code(code));
+        rest = after.trim_start_matches(['\r', '\n']);
+    }
+
+    inline_spans(rest, &mut spans);
+    spans
+}
+
+//=======================================================================//
+
+/// Pushes to `spans` the pieces of plain text, the markdown links, and the markdown images
+/// contained in `text`.
+#[inline]
+fn inline_spans<'a>(text: &'a str, spans: &mut Vec<MarkdownSpan<'a>>)
+{
+    let mut rest = text;
+    let mut start = 0;
+
+    while let Some(open) = rest[start..].find('[').map(|i| i + start)
+    {
+        let Some((close, end)) = rest[open + 1..].find("](").and_then(|close| {
+            let close = open + 1 + close;
+            rest[close + 2..].find(')').map(|end| (close, close + 2 + end))
+        })
+        else
+        {
+            start = open + 1;
+            continue;
+        };
+
+        let (text, target) = (&rest[open + 1..close], &rest[close + 2..end]);
+
+        if rest[..open].ends_with('!')
+        {
+            if open != 1
+            {
+                emphasis_spans(&rest[..open - 1], spans);
+            }
+
+            spans.push(MarkdownSpan::Image(text, target));
+        }
+        else
+        {
+            if open != 0
+            {
+                emphasis_spans(&rest[..open], spans);
+            }
+
+            spans.push(MarkdownSpan::Link(text, target));
+        }
+
+        rest = &rest[end + 1..];
+        start = 0;
+    }
+
+    if !rest.is_empty()
+    {
+        emphasis_spans(rest, spans);
+    }
+}
+
+//=======================================================================//
+
+/// Pushes to `spans` the pieces of plain text, the `**bold**` text, and the `*italic*` text
+/// contained in `text`.
+#[inline]
+fn emphasis_spans<'a>(text: &'a str, spans: &mut Vec<MarkdownSpan<'a>>)
+{
+    let mut rest = text;
+
+    while let Some(open) = rest.find('*')
+    {
+        let marker = if rest[open..].starts_with("**") { "**" } else { "*" };
+        let start = open + marker.len();
+
+        let Some(close) = rest[start..]
+            .find(marker)
+            .map(|i| i + start)
+            .filter(|close| *close != start)
+        else
+        {
+            break;
+        };
+
+        if open != 0
+        {
+            spans.push(MarkdownSpan::Text(&rest[..open]));
+        }
+
+        spans.push(
+            if marker.len() == 2
+            {
+                MarkdownSpan::Bold(&rest[start..close])
+            }
+            else
+            {
+                MarkdownSpan::Italic(&rest[start..close])
+            }
+        );
+
+        rest = &rest[close + marker.len()..];
+    }
+
+    if !rest.is_empty()
+    {
+        spans.push(MarkdownSpan::Text(rest));
+    }
+}
+
+//=======================================================================//
+
+/// Returns the plain text rendering of the manual explanation `exp`, with the links followed by
+/// their url, the images replaced by their alternative text, and the code blocks indented.
+#[inline]
+#[must_use]
+fn plain_text(exp: &str) -> String
+{
+    markdown_spans(exp)
+        .into_iter()
+        .map(|span| {
+            match span
+            {
+                MarkdownSpan::Text(text) |
+                MarkdownSpan::Bold(text) |
+                MarkdownSpan::Italic(text) => text.to_string(),
+                MarkdownSpan::Link(text, url) => format!("{text} ({url})"),
+                MarkdownSpan::Image(alt, _) => format!("[{alt}]"),
+                MarkdownSpan::Code(code) =>
+                {
+                    format!(
+                        "\n{}\n",
+                        code.lines()
+                            .map(|line| format!("    {line}"))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    )
+                }
+            }
+        })
+        .collect()
+}
+
+//=======================================================================//
+
+/// Returns the code of the `&[ManualSpan]` describing the manual explanation `exp`.
+#[inline]
+#[must_use]
+fn explanation_spans(exp: &str) -> String
+{
+    let mut code = "&[".to_string();
+
+    for span in markdown_spans(exp)
+    {
+        match span
+        {
+            MarkdownSpan::Text(text) => code.push_str(&format!("ManualSpan::Text(\"{text}\"), ")),
+            MarkdownSpan::Link(text, url) =>
+            {
+                code.push_str(&format!("ManualSpan::Link(\"{text}\", \"{url}\"), "));
+            },
+            MarkdownSpan::Image(alt, path) =>
+            {
+                code.push_str(&format!("ManualSpan::Image(\"{alt}\", \"{path}\"), "));
+            },
+            MarkdownSpan::Code(ini) =>
+            {
+                code.push_str(&format!("ManualSpan::Code({}), ", ini_tokens(ini)))
+            },
+            MarkdownSpan::Bold(text) => code.push_str(&format!("ManualSpan::Bold(\"{text}\"), ")),
+            MarkdownSpan::Italic(text) =>
+            {
+                code.push_str(&format!("ManualSpan::Italic(\"{text}\"), "))
+            },
+        };
+    }
+
+    code.push(']');
+    code
+}
+
+//=======================================================================//
+
+/// Returns the code of the `&[(ManualCodeToken, &str)]` highlighting the ini code `ini`.
+#[inline]
+#[must_use]
+fn ini_tokens(ini: &str) -> String
+{
+    let mut code = "&[".to_string();
+    let mut push = |token: &str, text: &str| {
+        if !text.is_empty()
+        {
+            code.push_str(&format!("(ManualCodeToken::{token}, \"{text}\"), "));
+        }
+    };
+
+    for (i, line) in ini.lines().enumerate()
+    {
+        if i != 0
+        {
+            push("Plain", "\\n");
+        }
+
+        let trimmed = line.trim_start();
+        push("Plain", &line[..line.len() - trimmed.len()]);
+
+        if trimmed.starts_with([';', '#'])
+        {
+            push("Comment", trimmed);
+        }
+        else if trimmed.starts_with('[')
+        {
+            push("Section", trimmed);
+        }
+        else if let Some((key, value)) = trimmed.split_once('=')
+        {
+            push("Key", key);
+            push("Separator", "=");
+            push("Value", value);
+        }
+        else
+        {
+            push("Plain", trimmed);
+        }
+    }
+
+    code.push(']');
+    code
+}
+
+//=======================================================================//
+
+/// Returns the cfg predicates of the sections of the manual in `docs`, listed in the optional
+/// `manual.cfg` file of `docs` as `section = predicate` lines, such as
+/// `free_draw = feature = "free_draw"`. Empty lines and lines starting with `#` are ignored.
+/// # Panics
+/// Panics if a line is not properly formatted or does not refer to a section of the manual.
+#[inline]
+#[must_use]
+fn manual_cfgs(docs: &std::path::Path) -> Vec<(String, String)>
+{
+    let Ok(file) = std::fs::read_to_string(docs.join("manual.cfg"))
+    else
+    {
+        return Vec::new();
+    };
+
+    let names = process_docs(
+        docs,
+        None,
+        |_| {},
+        |string, name, _| {
+            string.push_str(name);
+            string.push('\n');
+        },
+        |_, _, _, _| {},
+        |_, _| {}
+    );
+
+    file.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (section, predicate) = line
+                .split_once('=')
+                .unwrap_or_else(|| panic!("Invalid manual.cfg line {line}."));
+            let section = section.trim().replace('_', " ");
+            let name = names
+                .lines()
+                .find(|name| name.eq_ignore_ascii_case(&section))
+                .unwrap_or_else(|| panic!("Unknown manual.cfg section {section}."));
+
+            (name.to_string(), predicate.trim().to_string())
+        })
+        .collect()
+}
+
+//=======================================================================//
+
+/// Returns the `#[cfg]` attribute of the manual section `name` among `cfgs`, or an empty string if
+/// it has none.
+#[inline]
+#[must_use]
+fn section_cfg(cfgs: &[(String, String)], name: &str) -> String
+{
+    cfgs.iter()
+        .find_map(|(section, predicate)| {
+            (section == name).then(|| format!("#[cfg({predicate})]\n"))
+        })
+        .unwrap_or_default()
+}
+
+//=======================================================================//
+
+/// Returns the title of the texture editing manual entry `name`, the same as the one shown in the
+/// manual. The `texture` entry is titled "TEXTURE EDITING", the others are titled after their
+/// name, so that the texture docs of a tool can be split in several files.
+#[inline]
+#[must_use]
+fn texture_title(name: &str) -> String
+{
+    if name == "texture"
+    {
+        return "TEXTURE EDITING".to_string();
+    }
+
+    name.replace('_', " ").to_ascii_uppercase()
+}
+
+//=======================================================================//
+
+/// Returns the heading of the manual section `name`, the same as the one shown in the manual.
+#[inline]
+#[must_use]
+fn manual_heading(name: &str, item: &ManualItem) -> String
+{
+    match item
+    {
+        ManualItem::Tool => format!("{} TOOL", name.to_ascii_uppercase()),
+        _ => name.to_ascii_uppercase()
+    }
+}
+
+//=======================================================================//
+
+/// Reads the sections of the manual contained in the `manual` subdirectory of `docs` and generates
+/// their code through the provided functions, sorted as listed in `order` if any.
+/// Stands in for [`hill_vacuum_shared::process_docs`] until it takes the docs directory and the
+/// order as arguments instead of reading the `docs` directory of the current directory, at which
+/// point this function is to be removed.
+/// # Panics
+/// Panics if the manual files cannot be read.
+#[inline]
+fn process_docs<S, N, P, E>(
+    docs: &std::path::Path,
+    order: Option<&[String]>,
+    section_start: S,
+    section_name: N,
+    process_file: P,
+    section_end: E
+) -> String
+where
+    S: Fn(&mut String),
+    N: Fn(&mut String, &str, ManualItem),
+    P: Fn(&mut String, &str, String, ManualItem),
+    E: Fn(&mut String, bool)
+{
+    #[inline]
+    fn stem_chars(path: &std::path::Path) -> (impl Iterator<Item = char> + '_, ManualItem)
+    {
+        let mut chars = path.file_stem().unwrap().to_str().unwrap().chars();
+        let first = chars.next_value();
+        (chars.skip_while(|c| !c.is_alphabetic()), first.into())
+    }
+
+    #[inline]
+    #[must_use]
+    fn sorted_entries(dir: &std::path::Path) -> Vec<std::path::PathBuf>
+    {
+        let mut paths = std::fs::read_dir(dir)
+            .unwrap_or_else(|_| panic!("Could not read {}.", dir.display()))
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths
+    }
+
+    let mut string = String::new();
+    let mut dirs = sorted_entries(&docs.join("manual"));
+
+    if let Some(order) = order
+    {
+        let key = |dir: &std::path::PathBuf| stem_chars(dir).0.collect::<String>();
+        let keys = dirs.iter().map(key).collect::<Vec<_>>();
+        let unknown = order.iter().filter(|o| !keys.contains(o)).collect::<Vec<_>>();
+        let missing = keys.iter().filter(|k| !order.contains(k)).collect::<Vec<_>>();
+        assert!(
+            unknown.is_empty() && missing.is_empty(),
+            "Manual order mismatch, unknown sections {unknown:?}, missing sections {missing:?}."
+        );
+
+        dirs.sort_by_key(|dir| order.iter().position(|o| *o == key(dir)).unwrap());
+    }
+
+    let last_index = dirs.len() - 1;
+
+    for (i, dir) in dirs.into_iter().enumerate()
+    {
+        section_start(&mut string);
+
+        let (mut chars, item) = stem_chars(&dir);
+        let mut name = String::from(chars.next_value().to_ascii_uppercase());
+
+        for c in chars
+        {
+            name.push(if c == '_' { ' ' } else { c });
+        }
+
+        section_name(&mut string, &name, item);
+
+        for path in sorted_entries(&dir)
+        {
+            let (chars, item) = stem_chars(&path);
+
+            process_file(
+                &mut string,
+                &chars.collect::<String>(),
+                std::fs::read_to_string(&path).unwrap(),
+                item
+            );
+        }
+
+        section_end(&mut string, i == last_index);
+    }
+
+    string
+}
+
+//=======================================================================//
+
+/// Returns a `compile_error!` for each subtool of the manual in `docs` whose binds file is missing,
+/// if any.
+/// # Panics
+/// Panics if the manual files cannot be read.
+#[inline]
+#[must_use]
+fn missing_subtool_binds(docs: &std::path::Path) -> Option<TokenStream>
+{
+    let errors = process_docs(
+        docs,
+        None,
+        |_| {},
+        |_, _, _| {},
+        |string, name, _, item| {
+            if !matches!(item, ManualItem::Tool)
+            {
+                return;
+            }
+
+            let binds = docs.join(format!("subtools binds/{name}.md"));
+
+            if !binds.is_file()
+            {
+                string.push_str(&format!(
+                    "compile_error!({:?});",
+                    format!("Missing binds file {} of the subtool {name}.", binds.display())
+                ));
+            }
+        },
+        |_, _| {}
+    );
+
+    (!errors.is_empty()).then(|| errors.parse().unwrap())
+}
+
+//=======================================================================//
+
+/// Returns an item which fails compilation listing the `Tool` and `SubTool` variants without an
+/// entry in the manual in `docs`, through exhaustive matches over the documented ones, or an empty
+/// string if `complete` is false.
+/// # Panics
+/// Panics if the manual files cannot be read.
+#[inline]
+#[must_use]
+fn completeness_check(docs: &std::path::Path, complete: bool) -> String
+{
+    if !complete
+    {
+        return String::new();
+    }
+
+    let cfgs = manual_cfgs(docs);
+    let cfg = std::cell::RefCell::new(String::new());
+    let subtools = std::cell::RefCell::new(String::new());
+
+    let tools = process_docs(
+        docs,
+        None,
+        |_| {},
+        |string, name, item| {
+            *cfg.borrow_mut() = section_cfg(&cfgs, name);
+
+            if matches!(item, ManualItem::Tool)
+            {
+                string.push_str(&format!("{}Tool::{} => (),\n", cfg.borrow(), camel_case(name)));
+            }
+        },
+        |_, name, _, item| {
+            if matches!(item, ManualItem::Tool)
+            {
+                subtools.borrow_mut().push_str(&format!(
+                    "{}SubTool::{} => (),\n",
+                    cfg.borrow(),
+                    camel_case(name)
+                ));
+            }
+        },
+        |_, _| {}
+    );
+
+    format!(
+        "const _: () = {{
+            use crate::map::editor::state::ui::{{Tool, SubTool}};
+
+            /// Fails compilation if a tool or subtool is not documented in the manual.
+            #[allow(dead_code)]
+            fn documented(tool: Tool, subtool: SubTool)
+            {{
+                match tool
+                {{
+                    {tools}
+                }}
+
+                match subtool
+                {{
+                    {}
+                }}
+            }}
+        }};",
+        subtools.borrow()
+    )
+}
+
+//=======================================================================//
+
+/// Returns the code of the types of the manual data model and of the default egui renderer laid
+/// out according to `layout`, with the types having visibility `visibility`.
+/// The images are only loaded through `crate::embedded_assets::embedded_asset_path` if `images` is
+/// true.
+#[inline]
+#[must_use]
+fn manual_model(visibility: &str, layout: &ManualLayout, images: bool) -> String
+{
+    let column_width = layout.column_width;
+    let row = if layout.wrap { "horizontal_wrapped" } else { "horizontal" };
+    let (toc_separator, separator) = if layout.separators
+    {
+        ("ui.separator();", "if i + 1 != manual.len() { ui.separator(); }")
+    }
+    else
+    {
+        ("", "")
+    };
+    let image = if images
+    {
+        "ManualSpan::Image(alt, path) =>
+        {
+            ui.image(crate::embedded_assets::embedded_asset_path(path)).on_hover_text(*alt);
+        },"
+    }
+    else
+    {
+        "ManualSpan::Image(..) => (),"
+    };
+
+    format!(
+        "
+    /// An icon shown in the manual.
+    #[derive(Clone, Copy)]
+    {visibility} enum ManualIcon
+    {{
+        /// The icon of a tool.
+        Tool(crate::map::editor::state::ui::Tool),
+        /// The icon of a subtool.
+        SubTool(crate::map::editor::state::ui::SubTool)
+    }}
+
+    /// A piece of the text of a manual explanation.
+    #[derive(Clone, Copy)]
+    {visibility} enum ManualSpan
+    {{
+        /// Plain text.
+        Text(&'static str),
+        /// A link, described by its text and url.
+        Link(&'static str, &'static str),
+        /// An image, described by its alternative text and its path relative to the embedded \
+         assets.
+        Image(&'static str, &'static str),
+        /// A code block, described by its highlighted tokens.
+        Code(&'static [(ManualCodeToken, &'static str)]),
+        /// Bold text.
+        Bold(&'static str),
+        /// Italic text.
+        Italic(&'static str)
+    }}
+
+    /// The kind of a highlighted token of a manual code block.
+    #[derive(Clone, Copy)]
+    {visibility} enum ManualCodeToken
+    {{
+        /// An ini section header.
+        Section,
+        /// An ini key.
+        Key,
+        /// The separator between an ini key and its value.
+        Separator,
+        /// An ini value.
+        Value,
+        /// A comment.
+        Comment,
+        /// Any other text.
+        Plain
+    }}
+
+    /// The kind of a manual section.
+    #[derive(Clone, Copy)]
+    {visibility} enum ManualSectionKind
+    {{
+        /// A regular section.
+        Regular,
+        /// The section of a tool.
+        Tool(crate::map::editor::state::ui::Tool)
+    }}
+
+    /// The kind of a manual entry.
+    #[derive(Clone, Copy)]
+    {visibility} enum ManualEntryKind
+    {{
+        /// A regular entry.
+        Regular,
+        /// The entry of a subtool.
+        SubTool(crate::map::editor::state::ui::SubTool),
+        /// The texture editing entry.
+        Texture
+    }}
+
+    /// An entry of a manual section.
+    {visibility} struct ManualEntry
+    {{
+        /// The kind.
+        pub kind:        ManualEntryKind,
+        /// The title.
+        pub title:       &'static str,
+        /// The binds, if it is a subtool entry.
+        pub binds:       Option<&'static str>,
+        /// The explanation as shown in the manual, starting with the title and the binds if it
+        /// is a subtool entry.
+        pub explanation: &'static [ManualSpan],
+        /// The subsections introduced by the `###` subheadings of the explanation.
+        pub subsections: &'static [ManualSubsection]
+    }}
+
+    /// A subsection of a manual entry.
+    {visibility} struct ManualSubsection
+    {{
+        /// The title.
+        pub title:       &'static str,
+        /// The explanation.
+        pub explanation: &'static [ManualSpan]
+    }}
+
+    /// A section of the manual.
+    {visibility} struct ManualSection
+    {{
+        /// The heading.
+        pub name:    &'static str,
+        /// The kind.
+        pub kind:    ManualSectionKind,
+        /// The entries.
+        pub entries: &'static [ManualEntry]
+    }}
+
+    /// Shows an entry of the manual, with `left` on the left of the explanation.
+    #[inline]
+    fn show_explanation<L, R>(ui: &mut egui::Ui, left: L, explanation: R)
+    where
+        L: FnOnce(&mut egui::Ui),
+        R: FnOnce(&mut egui::Ui)
+    {{
+        ui.{row}(|ui| {{
+            egui_extras::StripBuilder::new(ui)
+                .size(egui_extras::Size::exact({column_width}f32))
+                .size(egui_extras::Size::remainder())
+                .horizontal(|mut strip| {{
+                    strip.cell(|ui| {{
+                        left(ui);
+                    }});
+
+                    strip.cell(|ui| {{
+                        explanation(ui);
+                    }});
+                }});
+        }});
+    }}
+
+    /// Shows a highlighted code block.
+    #[inline]
+    fn show_code(ui: &mut egui::Ui, tokens: &[(ManualCodeToken, &str)])
+    {{
+        let mut job = egui::text::LayoutJob::default();
+
+        for (token, text) in tokens
+        {{
+            let color = match token
+            {{
+                ManualCodeToken::Section => egui::Color32::from_rgb(86, 156, 214),
+                ManualCodeToken::Key => egui::Color32::from_rgb(156, 220, 254),
+                ManualCodeToken::Value => egui::Color32::from_rgb(206, 145, 120),
+                ManualCodeToken::Comment => egui::Color32::from_rgb(106, 153, 85),
+                ManualCodeToken::Separator | ManualCodeToken::Plain => ui.visuals().text_color()
+            }};
+
+            job.append(
+                text,
+                0f32,
+                egui::TextFormat::simple(egui::FontId::monospace(12f32), color)
+            );
+        }}
+
+        ui.group(|ui| {{
+            ui.label(job);
+        }});
+    }}
+
+    /// Shows the text of an explanation.
+    #[inline]
+    fn show_spans(ui: &mut egui::Ui, spans: &[ManualSpan])
+    {{
+        match spans
+        {{
+            [] => {{ ui.label(\"\"); }},
+            [ManualSpan::Text(text)] => {{ ui.label(*text); }},
+            _ if spans.iter().any(|span| matches!(span, ManualSpan::Code(_))) =>
+            {{
+                ui.vertical(|ui| {{
+                    let mut start = 0;
+
+                    for (i, span) in spans.iter().enumerate()
+                    {{
+                        if let ManualSpan::Code(tokens) = span
+                        {{
+                            if start != i
+                            {{
+                                show_spans(ui, &spans[start..i]);
+                            }}
+
+                            show_code(ui, tokens);
+                            start = i + 1;
+                        }}
+                    }}
+
+                    if start != spans.len()
+                    {{
+                        show_spans(ui, &spans[start..]);
+                    }}
+                }});
+            }},
+            _ =>
+            {{
+                ui.{row}(|ui| {{
+                    for span in spans
+                    {{
+                        match span
+                        {{
+                            ManualSpan::Text(text) => {{ ui.label(*text); }},
+                            ManualSpan::Link(text, url) => {{ ui.hyperlink_to(*text, *url); }},
+                            {image}
+                            ManualSpan::Bold(text) => {{ \
+         ui.label(egui::RichText::new(*text).strong()); }},
+                            ManualSpan::Italic(text) => {{ \
+         ui.label(egui::RichText::new(*text).italics()); }},
+                            ManualSpan::Code(tokens) => show_code(ui, tokens)
+                        }};
+                    }}
+                }});
+            }}
+        }};
+    }}
+
+    /// Shows the table of contents of `manual`, returning the index of the clicked section, if \
+         any.
+    #[inline]
+    fn show_manual_toc(ui: &mut egui::Ui, manual: &[ManualSection]) -> Option<usize>
+    {{
+        let mut clicked = None;
+
+        ui.horizontal_wrapped(|ui| {{
+            for (i, section) in manual.iter().enumerate()
+            {{
+                if ui.link(section.name).clicked()
+                {{
+                    clicked = i.into();
+                }}
+            }}
+        }});
+
+        clicked
+    }}
+
+    /// Shows `manual` preceded by its table of contents, drawing the icons of the tools and
+    /// subtools through `icon`. Clicking an entry of the table of contents expands the
+    /// corresponding section and scrolls to it.
+    #[inline]
+    {visibility} fn show_manual<F>(ui: &mut egui::Ui, manual: &[ManualSection], mut icon: F)
+    where
+        F: FnMut(&mut egui::Ui, ManualIcon)
+    {{
+        let target = show_manual_toc(ui, manual);
+        {toc_separator}
+
+        for (i, section) in manual.iter().enumerate()
+        {{
+            let open = (target == Some(i)).then_some(true);
+
+            let response = egui::CollapsingHeader::new(section.name).open(open).show(ui, |ui| {{
+                ui.vertical(|ui| {{
+                    if let ManualSectionKind::Tool(tool) = section.kind
+                    {{
+                        icon(ui, ManualIcon::Tool(tool));
+                    }}
+
+                    for entry in section.entries
+                    {{
+                        show_explanation(
+                            ui,
+                            |ui| {{
+                                match entry.kind
+                                {{
+                                    ManualEntryKind::SubTool(subtool) =>
+                                    {{
+                                        icon(ui, ManualIcon::SubTool(subtool));
+                                    }},
+                                    _ => {{ ui.label(entry.title); }}
+                                }};
+                            }},
+                            |ui| {{
+                                if entry.subsections.is_empty()
+                                {{
+                                    show_spans(ui, entry.explanation);
+                                    return;
+                                }}
+
+                                ui.vertical(|ui| {{
+                                    show_spans(ui, entry.explanation);
+
+                                    for subsection in entry.subsections
+                                    {{
+                                        ui.collapsing(subsection.title, |ui| {{
+                                            show_spans(ui, subsection.explanation);
+                                        }});
+                                    }}
+                                }});
+                            }}
+                        );
+                    }}
+                }})
+            }});
+
+            if open.is_some()
+            {{
+                response.header_response.scroll_to_me(Some(egui::Align::TOP));
+            }}
+
+            {separator}
+        }}
+    }}"
+    )
+}
+
+//=======================================================================//
+
+/// Returns the code of a `ManualEntry`.
+#[inline]
+#[must_use]
+fn manual_entry(kind: &str, title: &str, binds: Option<&str>, exp: &str) -> String
+{
+    let (exp, subsections) = explanation_subsections(exp);
+
+    format!(
+        "ManualEntry {{\nkind: {kind},\ntitle: \"{}\",\nbinds: {},\nexplanation: \
+         {},\nsubsections: &[{}]\n}},\n",
+        title.trim(),
+        binds.map_or_else(|| "None".to_string(), |binds| format!("Some(\"{binds}\")")),
+        explanation_spans(exp),
+        subsections
+            .into_iter()
+            .map(|(title, exp)| {
+                format!(
+                    "ManualSubsection {{ title: \"{title}\", explanation: {} }}, ",
+                    explanation_spans(exp)
+                )
+            })
+            .collect::<String>()
+    )
+}
+
+//=======================================================================//
+
+/// Returns the path of the changelog of the crate.
+#[inline]
+#[must_use]
+fn changelog_path() -> std::path::PathBuf
+{
+    std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("CHANGELOG.md")
+}
+
+//=======================================================================//
+
+/// Returns the item embedding the changelog of the crate in the generated code if the "What's new"
+/// section is shown, so that the crate is rebuilt when it changes.
+#[inline]
+#[must_use]
+fn track_changelog(whats_new: Option<usize>) -> String
+{
+    whats_new.map_or_else(String::new, |_| {
+        format!("const _: &str = include_str!({:?});\n", changelog_path().to_str().unwrap())
+    })
+}
+
+//=======================================================================//
+
+/// Returns the code of the "What's new" `ManualSection` listing the first `entries` versions of
+/// the changelog of the crate, each introduced by a `## ` heading.
+/// # Panics
+/// Panics if the changelog cannot be read.
+#[inline]
+#[must_use]
+fn whats_new_section(entries: usize) -> String
+{
+    let path = changelog_path();
+    let changelog = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Could not read {}.", path.display()));
+    let mut section = "ManualSection {\nname: \"WHAT'S NEW\",\nkind: \
+                       ManualSectionKind::Regular,\nentries: &[\n"
+        .to_string();
+
+    for version in changelog.split("\n## ").skip(1).take(entries)
+    {
+        let processed = manual_text(&format!("### {version}"));
+        let (title, exp) = processed.split_once('\n').unwrap_or((&processed, ""));
+        let exp = exp.lines().collect::<Vec<_>>().join("\n");
+        section.push_str(&manual_entry("ManualEntryKind::Regular", title, None, &exp));
+    }
+
+    section.push_str("]\n},\n");
+    section
+}
+
+//=======================================================================//
+
+/// Returns the amount of changelog versions listed by the `whats_new` manual option `value`.
+/// # Panics
+/// Panics if `value` is not a positive integer.
+#[inline]
+#[must_use]
+fn whats_new_entries(value: &str) -> usize
+{
+    match value.parse()
+    {
+        Ok(0) | Err(_) => panic!("Invalid whats_new value {value}, expected a positive integer."),
+        Ok(entries) => entries
+    }
+}
+
+//=======================================================================//
+
+/// Returns the value `value` of the bool manual option `key`.
+/// # Panics
+/// Panics if `value` is not a bool.
+#[inline]
+#[must_use]
+fn bool_option(key: &str, value: &str) -> bool
+{
+    value
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid {key} value {value}, expected a bool."))
+}
+
+//=======================================================================//
+
+/// Returns `code` with every string literal mentioning the Ctrl key replaced by an expression
+/// evaluating to the same literal mentioning the Cmd key on macOS, or `code` itself if `enabled` is
+/// false.
+#[inline]
+#[must_use]
+fn platform_keys(code: String, enabled: bool) -> String
+{
+    if !enabled
+    {
+        return code;
+    }
+
+    let mut result = String::with_capacity(code.len());
+    let mut rest = code.as_str();
+
+    while let Some(start) = rest.find('"')
+    {
+        result.push_str(&rest[..start]);
+
+        let bytes = rest.as_bytes();
+        let mut end = start + 1;
+
+        while bytes[end] != b'"'
+        {
+            if bytes[end] == b'\\'
+            {
+                end += 1;
+            }
+
+            end += 1;
+        }
+
+        let literal = &rest[start..=end];
+
+        if literal.contains("Ctrl")
+        {
+            result.push_str(&format!(
+                "if cfg!(target_os = \"macos\") {{ {} }} else {{ {literal} }}",
+                literal.replace("Ctrl", "Cmd")
+            ));
+        }
+        else
+        {
+            result.push_str(literal);
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+//=======================================================================//
+
+/// Returns the code of a block evaluating at compile time to the `&'static str` concatenation of
+/// `pieces`, the elements of an array of `&str` which can be individually cfg-gated.
+#[inline]
+#[must_use]
+fn const_concat(pieces: &str) -> String
+{
+    format!(
+        "{{
+        const PIECES: &[&str] = &[{pieces}];
+        const LEN: usize = {{
+            let mut len = 0;
+            let mut i = 0;
+
+            while i < PIECES.len()
+            {{
+                len += PIECES[i].len();
+                i += 1;
+            }}
+
+            len
+        }};
+        const BYTES: [u8; LEN] = {{
+            let mut bytes = [0u8; LEN];
+            let mut len = 0;
+            let mut i = 0;
+
+            while i < PIECES.len()
+            {{
+                let piece = PIECES[i].as_bytes();
+                let mut j = 0;
+
+                while j < piece.len()
+                {{
+                    bytes[len + j] = piece[j];
+                    j += 1;
+                }}
+
+                len += piece.len();
+                i += 1;
+            }}
+
+            bytes
+        }};
+        const STRING: &str = match std::str::from_utf8(&BYTES)
+        {{
+            Ok(string) => string,
+            Err(_) => unreachable!()
+        }};
+
+        STRING
+    }}"
+    )
+}
+
+//=======================================================================//
+
+/// Returns the code of the `&[ManualSection]` containing the manual in `docs`, with the sections
+/// sorted by `order`, preceded by the "What's new" section listing the first `whats_new` versions
+/// of the changelog, if any.
+/// # Panics
+/// Panics if the docs or the changelog cannot be read.
+#[inline]
+#[must_use]
+fn manual_data(docs: &std::path::Path, order: Option<&[String]>, whats_new: Option<usize>)
+    -> String
+{
+    const TOOLS: &str = "crate::map::editor::state::ui";
+
+    let cfgs = manual_cfgs(docs);
+
+    let data = process_docs(
+        docs,
+        order,
+        |_| {},
+        |string, name, item| {
+            let kind = match item
+            {
+                ManualItem::Regular => "ManualSectionKind::Regular".to_string(),
+                ManualItem::Tool =>
+                {
+                    format!("ManualSectionKind::Tool({TOOLS}::Tool::{})", camel_case(name))
+                },
+                ManualItem::Texture => unreachable!()
+            };
+
+            string.push_str(&format!(
+                "{}ManualSection {{\nname: \"{}\",\nkind: {kind},\nentries: &[\n",
+                section_cfg(&cfgs, name),
+                manual_heading(name, &item)
+            ));
+        },
+        |string, name, file, item| {
+            let processed = manual_text(&file);
+
+            let (kind, title, binds, exp) = match item
+            {
+                ManualItem::Regular =>
+                {
+                    let (command, exp) = processed.split_once('\n').unwrap_or((&processed, ""));
+                    let exp = exp.lines().collect::<Vec<_>>().join("\n");
+                    ("ManualEntryKind::Regular".to_string(), command.to_string(), None, exp)
+                },
+                ManualItem::Tool =>
+                {
+                    let binds =
+                        std::fs::read_to_string(docs.join(format!("subtools binds/{name}.md")))
+                            .unwrap();
+
+                    (
+                        format!("ManualEntryKind::SubTool({TOOLS}::SubTool::{})", camel_case(name)),
+                        processed.lines().next_value().to_string(),
+                        Some(binds),
+                        subtool_explanation(docs, name, &processed)
+                    )
+                },
+                ManualItem::Texture =>
+                {
+                    ("ManualEntryKind::Texture".to_string(), texture_title(name), None, processed)
+                },
+            };
+
+            string.push_str(&manual_entry(&kind, &title, binds.as_deref(), &exp));
+        },
+        |string, _| string.push_str("]\n},\n")
+    );
+
+    format!("&[\n{}{data}]", whats_new.map(whats_new_section).unwrap_or_default())
+}
+
+//=======================================================================//
+
+/// Generates the built-in manual from some of the markdown files in the `docs` directory.
+/// The docs directory can be specified as a string relative to the directory of the crate's
+/// manifest. Editing a file of the manual causes the crate to be rebuilt, adding or removing one
+/// does not. The markdown links in the explanations are shown as hyperlinks, and the markdown
+/// images as the images with the same path relative to the embedded assets, loaded through a
+/// `crate::embedded_assets::embedded_asset_path` function which is only required if the docs
+/// contain images. The `**bold**` and `*italic*` spans are shown with the corresponding text
+/// styles. The ```ini code blocks are shown as monospace highlighted code blocks, and the `###`
+/// subheadings following the first line of a file introduce nested collapsing subsections.
+/// Every subtool must have its binds described in a file of the `subtools binds` subdirectory of
+/// the docs directory named after it, otherwise a compile error is emitted.
+/// The texture editing docs of a tool can be split in several files starting with `X`, such as
+/// `X01 - texture_scale.md`, each shown as its own entry.
+/// The manual starts with a table of contents whose entries expand the corresponding section and
+/// scroll to it.
+/// Sections can be compiled out by listing their cfg predicates in a `manual.cfg` file of the docs
+/// directory, one `section = predicate` line each, such as `free_draw = feature = "free_draw"`.
+/// The predicates also apply to [`generate_manual_index`], [`generate_manual_text`], and
+/// [`generate_manual_html`].
+/// The `whats_new` option adds a "What's new" section at the top of the manual listing the given
+/// amount of most recent versions of the `CHANGELOG.md` of the crate, each introduced by a `## `
+/// heading, such as `whats_new = 3`.
+/// The layout can be adjusted through the `column_width` option, the width of the column of the
+/// commands and subtool icons (250 by default), the `wrap` option, whether the entries wrap their
+/// content (true by default), and the `separators` option, whether the sections are separated
+/// (true by default).
+/// Compilation fails listing the `Tool` and `SubTool` variants that are not documented in the
+/// manual, unless the `complete` option is set to false.
+/// If the `platform_keys` option is set to true the texts mentioning the Ctrl key mention the Cmd
+/// key instead when compiling for macOS.
+/// The manual is shown through the data model and the default renderer generated by
+/// [`generate_manual_data`].
+/// If the `language` option is specified the manual is localized in the subdirectories of the docs
+/// directory, such as `docs/en` and `docs/it`, and the shown one is selected by the value of the
+/// option, a `ManualLanguage` generated by [`generate_manual_languages`].
+/// The `order` option pins the order of the sections, otherwise sorted by the names of their
+/// directories. It must list every section, by the name of its directory without the leading index,
+/// such as `order = ["general", "edit", "free_draw"]`.
+/// # Examples
+/// ```ignore
+/// generate_manual!();
+/// generate_manual!("assets/docs");
+/// generate_manual!("docs", language = self.language);
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual(stream: TokenStream) -> TokenStream
+{
+    const ICON: &str = "
+    use crate::map::editor::state::core::tool::ToolInterface;
+
+    let icon = |ui: &mut egui::Ui, icon: ManualIcon| {
+        match icon
+        {
+            ManualIcon::Tool(tool) => tools_buttons.image(ui, tool),
+            ManualIcon::SubTool(subtool) => tools_buttons.image(ui, subtool)
+        };
+    };";
+
+    let (docs, options) = manual_arguments(stream);
+    let mut language = None;
+    let mut order = None;
+    let mut whats_new = None;
+    let mut complete = true;
+    let mut platform = false;
+    let mut layout = ManualLayout::default();
+
+    for (key, value) in options
+    {
+        if layout.set(&key, &value)
+        {
+            continue;
+        }
+
+        match key.as_str()
+        {
+            "language" => language = value.into(),
+            "order" => order = manual_order(&value).into(),
+            "whats_new" => whats_new = whats_new_entries(&value).into(),
+            "complete" => complete = bool_option(&key, &value),
+            "platform_keys" => platform = bool_option(&key, &value),
+            key => panic!("Unknown generate_manual option {key}.")
+        };
+    }
+
+    let Some(language) = language
+    else
+    {
+        if let Some(error) = missing_subtool_binds(&docs)
+        {
+            return error;
+        }
+
+        let data = manual_data(&docs, order.as_deref(), whats_new);
+
+        return format!(
+            "{}\n{}\n{ICON}\n{}{}static MANUAL: &[ManualSection] = {};\nshow_manual(ui, MANUAL, \
+             icon);",
+            manual_model("", &layout, data.contains("ManualSpan::Image(")),
+            track_changelog(whats_new),
+            track_docs(&docs),
+            completeness_check(&docs, complete),
+            platform_keys(data, platform)
+        )
+        .parse()
+        .unwrap();
+    };
+
+    let mut dispatch = String::new();
+    let mut images = false;
+
+    for (code, locale) in manual_locales(&docs)
+    {
+        if let Some(error) = missing_subtool_binds(&locale)
+        {
+            return error;
+        }
+
+        let data = manual_data(&locale, order.as_deref(), whats_new);
+        images |= data.contains("ManualSpan::Image(");
+
+        dispatch.push_str(&format!(
+            "ManualLanguage::{} => {{\n{}{}static MANUAL: &[ManualSection] = {};\nshow_manual(ui, \
+             MANUAL, icon);\n}},\n",
+            camel_case(&code),
+            track_docs(&locale),
+            completeness_check(&locale, complete),
+            platform_keys(data, platform)
+        ));
+    }
+
+    format!(
+        "{}\n{}\n{ICON}\nmatch {language}\n{{\n{dispatch}}}",
+        manual_model("", &layout, images),
+        track_changelog(whats_new)
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `MANUAL` static containing the data model of the manual shown by
+/// [`generate_manual`], the types describing it, and a `show_manual` function rendering it with
+/// egui, so that the manual can be shown with custom widgets.
+/// Accepts the docs directory and the `order`, `whats_new`, `complete`, `platform_keys`, and layout
+/// options of [`generate_manual`], plus a `vis` option setting the visibility of the generated
+/// items, private by default.
+/// # Examples
+/// ```ignore
+/// generate_manual_data!("docs", vis = pub(crate));
+///
+/// show_manual(ui, MANUAL, |ui, icon| {
+///     match icon
+///     {
+///         ManualIcon::Tool(tool) => tools_buttons.image(ui, tool),
+///         ManualIcon::SubTool(subtool) => tools_buttons.image(ui, subtool)
+///     };
+/// });
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual_data(stream: TokenStream) -> TokenStream
+{
+    let (docs, options) = manual_arguments(stream);
+    let mut visibility = String::new();
+    let mut order = None;
+    let mut whats_new = None;
+    let mut complete = true;
+    let mut platform = false;
+    let mut layout = ManualLayout::default();
+
+    for (key, value) in options
+    {
+        if layout.set(&key, &value)
+        {
+            continue;
+        }
+
+        match key.as_str()
+        {
+            "vis" => visibility = value,
+            "order" => order = manual_order(&value).into(),
+            "whats_new" => whats_new = whats_new_entries(&value).into(),
+            "complete" => complete = bool_option(&key, &value),
+            "platform_keys" => platform = bool_option(&key, &value),
+            key => panic!("Unknown generate_manual_data option {key}.")
+        };
+    }
+
+    if let Some(error) = missing_subtool_binds(&docs)
+    {
+        return error;
+    }
+
+    let data = manual_data(&docs, order.as_deref(), whats_new);
+
+    format!(
+        "{}\n{}{}{}{visibility} static MANUAL: &[ManualSection] = {};",
+        manual_model(&visibility, &layout, data.contains("ManualSpan::Image(")),
+        track_docs(&docs),
+        completeness_check(&docs, complete),
+        track_changelog(whats_new),
+        platform_keys(data, platform)
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `ManualLanguage` enum containing the languages of the manual localized in the
+/// subdirectories of the docs directory, such as `docs/en` and `docs/it`, to be passed to
+/// [`generate_manual`]. The default language is the first in alphabetical order.
+/// Accepts the docs directory as [`generate_manual`].
+/// # Examples
+/// ```ignore
+/// generate_manual_languages!();
+/// // Equivalent to
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// pub enum ManualLanguage
+/// {
+///     #[default]
+///     En,
+///     It
+/// }
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual_languages(stream: TokenStream) -> TokenStream
+{
+    let docs = docs_directory(stream);
+    let locales = manual_locales(&docs);
+    let mut variants = "#[default]\n".to_string();
+    let mut all = String::new();
+    let mut codes = String::new();
+
+    for (code, _) in &locales
+    {
+        let variant = camel_case(code);
+        variants.push_str(&format!("{variant},\n"));
+        all.push_str(&format!("Self::{variant}, "));
+        codes.push_str(&format!("Self::{variant} => \"{code}\",\n"));
+    }
+
+    format!(
+        "
+        /// The languages of the manual.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub enum ManualLanguage
+        {{
+            {variants}
+        }}
+
+        impl ManualLanguage
+        {{
+            /// All the languages.
+            pub const ALL: [Self; {}] = [{all}];
+
+            /// The name of the docs subdirectory of the language.
+            #[inline]
+            #[must_use]
+            pub const fn code(self) -> &'static str
+            {{
+                match self
+                {{
+                    {codes}
+                }}
+            }}
+        }}
+        ",
+        locales.len()
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `MANUAL_TEXT` constant containing a plain text rendering of the manual generated by
+/// [`generate_manual`], which can be printed without the UI.
+/// Accepts the docs directory and the `order` and `platform_keys` options of [`generate_manual`].
+/// # Examples
+/// ```ignore
+/// generate_manual_text!();
+/// // Equivalent to
+/// pub const MANUAL_TEXT: &str = "GENERAL\n=======\n\nBrush\n    A brush is...";
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual_text(stream: TokenStream) -> TokenStream
+{
+    #[inline]
+    fn push_entry(string: &mut String, title: &str, exp: &str)
+    {
+        string.push_str(title.trim());
+        string.push('\n');
+
+        let (exp, subsections) = explanation_subsections(exp);
+        push_explanation(string, exp, "    ");
+
+        for (title, exp) in subsections
+        {
+            string.push_str(&format!("    {title}\n"));
+            push_explanation(string, exp, "        ");
+        }
+
+        string.push('\n');
+    }
+
+    #[inline]
+    fn push_explanation(string: &mut String, exp: &str, indent: &str)
+    {
+        for line in plain_text(exp).lines().map(str::trim_end)
+        {
+            if !line.is_empty()
+            {
+                string.push_str(indent);
+                string.push_str(line);
+            }
+
+            string.push('\n');
+        }
+    }
+
+    let (docs, order, platform) = ordered_docs_directory(stream);
+
+    if let Some(error) = missing_subtool_binds(&docs)
+    {
+        return error;
+    }
+
+    let cfgs = manual_cfgs(&docs);
+
+    let body = process_docs(
+        &docs,
+        order.as_deref(),
+        |_| {},
+        |string, name, item| {
+            let heading = manual_heading(name, &item);
+            string.push_str(&format!(
+                "{}\"{heading}\n{}\n\n",
+                section_cfg(&cfgs, name),
+                "=".repeat(heading.len())
+            ));
+        },
+        |string, name, file, item| {
+            let processed = manual_text(&file);
+
+            match item
+            {
+                ManualItem::Regular =>
+                {
+                    let (command, exp) = processed.split_once('\n').unwrap_or((&processed, ""));
+                    push_entry(string, command, exp);
+                },
+                ManualItem::Tool =>
+                {
+                    let exp = subtool_explanation(&docs, name, &processed);
+                    let (title, exp) = exp.split_once('\n').unwrap_or((&exp, ""));
+                    push_entry(string, title, exp);
+                },
+                ManualItem::Texture => push_entry(string, &texture_title(name), &processed)
+            };
+        },
+        |string, last| {
+            string.truncate(string.trim_end().len());
+            string.push_str(if last { "\n\",\n" } else { "\n\n\",\n" });
+        }
+    );
+
+    format!(
+        "{}pub const MANUAL_TEXT: &str = {};",
+        track_docs(&docs),
+        const_concat(&platform_keys(body, platform))
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `manual_html` function returning a standalone HTML page containing the manual
+/// generated by [`generate_manual`], so that it can be browsed outside the editor.
+/// Accepts the docs directory and the `order` and `platform_keys` options of [`generate_manual`].
+/// # Examples
+/// ```ignore
+/// generate_manual_html!();
+/// std::fs::write("manual.html", manual_html()).unwrap();
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual_html(stream: TokenStream) -> TokenStream
+{
+    const HEAD: &str = "<!DOCTYPE html>\n<html>\n<head>\n<meta \
+                        charset=\\\"utf-8\\\">\n<title>Manual</title>\n</head>\n";
+
+    #[inline]
+    #[must_use]
+    fn escape(text: &str) -> String
+    {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace("\\\"", "&quot;")
+            .replace('\'', "&#39;")
+    }
+
+    #[inline]
+    fn push_entry(string: &mut String, title: &str, exp: &str)
+    {
+        let (exp, subsections) = explanation_subsections(exp);
+        let mut dd = explanation_html(exp);
+
+        for (title, exp) in subsections
+        {
+            dd.push_str(&format!(
+                "\n<details><summary>{}</summary>{}</details>",
+                escape(title),
+                explanation_html(exp)
+            ));
+        }
+
+        string.push_str(&format!("<dt>{}</dt>\n<dd>{dd}</dd>\n", escape(title.trim())));
+    }
+
+    #[inline]
+    #[must_use]
+    fn explanation_html(exp: &str) -> String
+    {
+        let mut dd = String::new();
+
+        for span in markdown_spans(exp.trim())
+        {
+            match span
+            {
+                MarkdownSpan::Text(text) => dd.push_str(&escape(text)),
+                MarkdownSpan::Link(text, url) =>
+                {
+                    dd.push_str(&format!("<a href=\\\"{}\\\">{}</a>", escape(url), escape(text)));
+                },
+                MarkdownSpan::Image(alt, path) =>
+                {
+                    dd.push_str(&format!(
+                        "<img src=\\\"{}\\\" alt=\\\"{}\\\">",
+                        escape(path),
+                        escape(alt)
+                    ));
+                },
+                MarkdownSpan::Bold(text) =>
+                {
+                    dd.push_str(&format!("<strong>{}</strong>", escape(text)))
+                },
+                MarkdownSpan::Italic(text) => dd.push_str(&format!("<em>{}</em>", escape(text))),
+                MarkdownSpan::Code(code) =>
+                {
+                    dd.push_str(&format!(
+                        "<pre><code class=\\\"language-ini\\\">{}</code></pre>",
+                        escape(code).lines().collect::<Vec<_>>().join("&#10;")
+                    ));
+                }
+            };
+        }
+
+        dd.lines().collect::<Vec<_>>().join("<br>\n")
+    }
+
+    let (docs, order, platform) = ordered_docs_directory(stream);
+
+    if let Some(error) = missing_subtool_binds(&docs)
+    {
+        return error;
+    }
+
+    let cfgs = manual_cfgs(&docs);
+
+    let body = process_docs(
+        &docs,
+        order.as_deref(),
+        |_| {},
+        |string, name, item| {
+            string.push_str(&format!(
+                "{}\"<section>\n<h2>{}</h2>\n<dl>\n",
+                section_cfg(&cfgs, name),
+                escape(&manual_heading(name, &item))
+            ));
+        },
+        |string, name, file, item| {
+            let processed = manual_text(&file);
+
+            match item
+            {
+                ManualItem::Regular =>
+                {
+                    let (command, exp) = processed.split_once('\n').unwrap_or((&processed, ""));
+                    push_entry(string, command, exp);
+                },
+                ManualItem::Tool =>
+                {
+                    let exp = subtool_explanation(&docs, name, &processed);
+                    let (title, exp) = exp.split_once('\n').unwrap_or((&exp, ""));
+                    push_entry(string, title, exp);
+                },
+                ManualItem::Texture => push_entry(string, &texture_title(name), &processed)
+            };
+        },
+        |string, _| string.push_str("</dl>\n</section>\n\",\n")
+    );
+
+    format!(
+        "
+        {}
+
+        /// Returns a standalone HTML page containing the manual.
+        #[inline]
+        #[must_use]
+        pub const fn manual_html() -> &'static str
+        {{
+            {}
+        }}
+        ",
+        track_docs(&docs),
+        const_concat(&platform_keys(
+            format!("\"{HEAD}<body>\n<h1>Manual</h1>\n\",\n{body}\"</body>\n</html>\n\""),
+            platform
+        ))
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `MANUAL_INDEX` static containing the pairs of section heading and entry title of the
+/// manual generated by [`generate_manual`], so that the manual can be searched.
+/// The subtool entries are titled with the first line of their explanation.
+/// Accepts the docs directory and the `order` and `platform_keys` options of [`generate_manual`].
+/// # Examples
+/// ```ignore
+/// generate_manual_index!();
+/// // Equivalent to
+/// pub static MANUAL_INDEX: &[(&str, &str)] = &[("GENERAL", "Brush"), ..., ("ZOOM TOOL", ...)];
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_manual_index(stream: TokenStream) -> TokenStream
+{
+    let (docs, order, platform) = ordered_docs_directory(stream);
+    let cfgs = manual_cfgs(&docs);
+    let heading = std::cell::RefCell::new((String::new(), String::new()));
+
+    let body = process_docs(
+        &docs,
+        order.as_deref(),
+        |_| {},
+        |_, name, item| {
+            *heading.borrow_mut() = (manual_heading(name, &item), section_cfg(&cfgs, name));
+        },
+        |string, name, file, item| {
+            let title = match item
+            {
+                ManualItem::Regular | ManualItem::Tool =>
+                {
+                    manual_text(&file).lines().next_value().to_string()
+                },
+                ManualItem::Texture => texture_title(name)
+            };
+
+            let heading = heading.borrow();
+            string.push_str(&format!("{}(\"{}\", \"{title}\"),\n", heading.1, heading.0));
+        },
+        |_, _| {}
+    );
+
+    format!(
+        "{}pub static MANUAL_INDEX: &[(&str, &str)] = &[{}];",
+        track_docs(&docs),
+        platform_keys(body, platform)
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a `subtool_explanation` function returning the plain text explanation of a subtool
+/// shown in the manual generated by [`generate_manual`], binds included, so that it can be reused
+/// by the tooltips of the editor.
+/// Accepts the docs directory and the `platform_keys` option of [`generate_manual`].
+/// The explanations of the sections compiled out through `manual.cfg` are compiled out as well,
+/// and the undocumented subtools have an empty explanation.
+/// # Examples
+/// ```ignore
+/// generate_subtool_explanation!();
+/// // Equivalent to
+/// pub const fn subtool_explanation(subtool: SubTool) -> &'static str
+/// {
+///     match subtool
+///     {
+///         SubTool::EntityDragSpawn => "Drag spawn subtool (Alt + directional key or cursor drag)\n...",
+///         ...
+///     }
+/// }
+/// ```
+/// # Panics
+/// Panics if `stream` is not properly formatted or the docs cannot be read.
+#[proc_macro]
+pub fn generate_subtool_explanation(stream: TokenStream) -> TokenStream
+{
+    let (docs, options) = manual_arguments(stream);
+    let mut platform = false;
+
+    for (key, value) in options
+    {
+        match key.as_str()
+        {
+            "platform_keys" => platform = bool_option(&key, &value),
+            key => panic!("Unknown generate_subtool_explanation option {key}.")
+        };
+    }
+
+    if let Some(error) = missing_subtool_binds(&docs)
+    {
+        return error;
+    }
+
+    let cfgs = manual_cfgs(&docs);
+    let cfg = std::cell::RefCell::new(String::new());
+
+    let arms = process_docs(
+        &docs,
+        None,
+        |_| {},
+        |_, name, _| *cfg.borrow_mut() = section_cfg(&cfgs, name),
+        |string, name, file, item| {
+            if !matches!(item, ManualItem::Tool)
+            {
+                return;
+            }
+
+            let exp = subtool_explanation(&docs, name, &manual_text(&file));
+            let (exp, subsections) = explanation_subsections(&exp);
+            let mut text = plain_text(exp);
+
+            for (title, exp) in subsections
+            {
+                text.push_str(&format!("\n{title}\n{}", plain_text(exp)));
+            }
+
+            string.push_str(&format!(
+                "{}SubTool::{} => \"{}\",\n",
+                cfg.borrow(),
+                camel_case(name),
+                text.lines().map(str::trim_end).collect::<Vec<_>>().join("\n")
+            ));
+        },
+        |_, _| {}
+    );
+
+    format!(
+        "{}pub const fn subtool_explanation(subtool: crate::map::editor::state::ui::SubTool) -> \
+         &'static str\n{{\nuse crate::map::editor::state::ui::SubTool;\n\nmatch \
+         subtool\n{{\n{}#[allow(unreachable_patterns)]\n_ => \"\"\n}}\n}}",
+        track_docs(&docs),
+        platform_keys(arms, platform)
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates a function which associates a f32 value representing a certain height to each provided
+/// enum match arm.
+/// The heights are assigned at increasing intervals following the order of the variants, unless
+/// pinned with `Variant = height` in the background, grid, entities, or ui sections, in which
+/// case the following variants are assigned the heights they would have had without the pinned
+/// one, and the following sections start above the highest pinned height. Compilation fails if a
+/// pinned height is less than a step away from the height of another variant of its section, or
+/// if it is beneath the start of its section, or not beneath the entities for the background
+/// section.
+/// The pinned heights of the background variants apply to `background_height`, the ones of the
+/// grid variants to `line_height`, the ones of the entities variants to `entity_height`, and the
+/// ones of the ui variants to `square_hgl_height`.
+/// Any variant can also be given a default color with `Variant = #rrggbb` (or `#rrggbbaa`, or
+/// `"#rrggbb"` if the hex digits cannot be lexed), placed after the height if both are
+/// specified. If at least one default color is specified a `default_color` method returning
+/// the RGBA bytes is generated, in which case every variant must have exactly one.
+/// A second default color can follow the first one to specify the default of the light theme,
+/// returned by the generated `default_light_color` method, whose settings are stored under the
+/// keys returned by `light_config_file_key`.
+/// The heights of the background, grid, entities, and ui sections are assigned at intervals of
+/// 1, 1, the textures height range plus 1, and 1 respectively, unless a different step is
+/// specified in the section header, e.g. `ui(step = 0.5):`. The step of the grid section applies
+/// to every line height.
+/// The generated `selected_height` method returns the entity height raised by half the entities
+/// step, at most 0.5.
+/// An optional background section can be placed between the extensions and grid ones, whose
+/// variants are assigned the heights of `background_height`, beneath the entities.
+/// A variant can only be listed once, except for entities variants which can be listed again in
+/// the ui section, otherwise a compilation error is emitted.
+/// A variant can be declared as an alias of another one with `alias B = A` in any section, in
+/// which case it is drawn at the same heights and has the same config file key, label, category,
+/// and default color.
+/// The input can start with `max_height = value,`, in which case compilation fails if any of the
+/// generated heights exceeds `value`.
+/// The generated `category` method requires the enum created by [`color_category`] to be in
+/// scope.
+#[allow(clippy::missing_panics_doc)]
+#[proc_macro]
+pub fn color_enum(stream: TokenStream) -> TokenStream
+{
+    #[inline]
+    fn push_key_and_label(item: &str, source: &str, label_func: &mut String, key_func: &mut String)
+    {
+        label_func.push_str(&format!("Self::{item} => \"{}\",\n", label(source)));
+        key_func.push_str(&format!("Self::{item} => \"{}\",\n", snake_case(source)));
+    }
+
+    #[inline]
+    #[must_use]
+    fn duplicate_error(
+        sections: [(&str, &[ColorEntry]); 6],
+        aliases: &[(String, String)]
+    ) -> Option<TokenStream>
+    {
+        let mut listed = Vec::<(&String, &str)>::new();
+
+        for (section, entries) in sections
+        {
+            for variant in entries.iter().flat_map(|entry| &entry.variants)
+            {
+                if let Some((_, first)) = listed
+                    .iter()
+                    .find(|(v, s)| *v == variant && !(*s == "entities" && section == "ui"))
+                {
+                    let error = if *first == section
+                    {
+                        format!("Color {variant} is listed twice in the {section} section.")
+                    }
+                    else
+                    {
+                        format!(
+                            "Color {variant} is listed in both the {first} and {section} sections."
+                        )
+                    };
+
+                    return Some(format!("compile_error!(\"{error}\");").parse().unwrap());
+                }
+
+                listed.push((variant, section));
+            }
+        }
+
+        for (i, (alias, _)) in aliases.iter().enumerate()
+        {
+            if let Some((_, section)) = listed.iter().find(|(v, _)| *v == alias)
+            {
+                return Some(
+                    format!(
+                        "compile_error!(\"Alias {alias} is already listed in the {section} \
+                         section.\");"
+                    )
+                    .parse()
+                    .unwrap()
+                );
+            }
+
+            if aliases[..i].iter().any(|(a, _)| a == alias)
+            {
+                return Some(
+                    format!("compile_error!(\"Alias {alias} is declared twice.\");")
+                        .parse()
+                        .unwrap()
+                );
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    fn hex_color(value: &str) -> [u8; 4]
+    {
+        let hex = value.trim_matches('"').trim_start_matches('#');
+        assert!(
+            matches!(hex.len(), 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit()),
+            "Invalid color {value}."
+        );
+
+        let mut color = [u8::MAX; 4];
+
+        for (i, c) in color.iter_mut().enumerate().take(hex.len() / 2)
+        {
+            *c = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).unwrap();
+        }
+
+        color
+    }
+
+    #[inline]
+    fn push_color(entry: &mut ColorEntry, value: &str)
+    {
+        let color = hex_color(value).into();
+
+        if entry.color.is_none()
+        {
+            entry.color = color;
+        }
+        else
+        {
+            assert!(entry.light_color.is_none(), "Too many default colors {value}.");
+            entry.light_color = color;
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    fn single_entry(vec: Vec<ColorEntry>, section: &str) -> ColorEntry
+    {
+        assert!(vec.len() == 1, "The {section} section must contain a single color.");
+        let entry = vec.into_iter().next().unwrap();
+        assert!(
+            entry.variants.len() == 1 && entry.height.is_none(),
+            "The {section} color cannot be aliased or pinned."
+        );
+        entry
+    }
+
+    #[inline]
+    #[must_use]
+    fn section_iter_func(name: &str, count: &str, section: &str, entries: &[ColorEntry]) -> String
+    {
+        let mut func = format!(
+            "
+    /// Returns an iterator to the [`Color`]s of the {section} section.
+    #[inline]
+    pub fn {name}() -> impl ExactSizeIterator<Item = Self> + Clone
+    {{
+        [
+    "
+        );
+        let mut variants = Vec::new();
+
+        for variant in entries.iter().flat_map(|entry| &entry.variants)
+        {
+            if !variants.contains(&variant)
+            {
+                func.push_str(&format!("Self::{variant},\n"));
+                variants.push(variant);
+            }
+        }
+
+        func.push_str(&format!(
+            "].into_iter()\n}}
+
+    /// The amount of [`Color`]s of the {section} section.
+    pub const {count}: usize = {};",
+            variants.len()
+        ));
+        func
+    }
+
+    #[inline]
+    #[must_use]
+    fn section_predicate_func(name: &str, section: &str, entries: &[ColorEntry]) -> String
+    {
+        format!(
+            "
+    /// Whether [`Color`] is listed in the {section} section, aliases included.
+    #[inline]
+    #[must_use]
+    pub const fn {name}(self) -> bool {{ {} }}",
+            if entries.is_empty()
+            {
+                "false".to_string()
+            }
+            else
+            {
+                format!(
+                    "matches!(self, {})",
+                    entries
+                        .iter()
+                        .map(ColorEntry::pattern)
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                )
+            }
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    fn section_step(group: &Group) -> f32
+    {
+        let mut stream = group.stream().into_iter();
+        assert!(stream.next_value().to_string() == "step", "Invalid section option {group}.");
+        assert!(
+            matches!(stream.next_value(), TokenTree::Punct(p) if p.as_char() == '='),
+            "Expected = after step."
+        );
+
+        let step = stream.next_value().to_string();
+        let value = step
+            .trim_end_matches("f32")
+            .parse::<f32>()
+            .unwrap_or_else(|_| panic!("Invalid section step {step}."));
+        assert!(value > 0f32, "Section step {step} is not positive.");
+        value
+    }
+
+    #[inline]
+    #[must_use]
+    fn extract<I: Iterator<Item = TokenTree>>(
+        stream: &mut I,
+        end_tags: &[&str],
+        aliases: &mut Vec<(String, String)>,
+        steps: &mut Vec<(String, f32)>
+    ) -> (Vec<ColorEntry>, Option<String>)
+    {
+        let mut vec: Vec<ColorEntry> = Vec::new();
+
+        while let Some(item) = stream.next()
+        {
+            if let TokenTree::Punct(p) = item
+            {
+                let c = p.as_char();
+
+                match c
+                {
+                    ',' => (),
+                    '|' =>
+                    {
+                        let item = stream.next_value().to_string();
+                        vec.last_mut().unwrap().variants.push(item);
+                    },
+                    '#' =>
+                    {
+                        push_color(vec.last_mut().unwrap(), &stream.next_value().to_string());
+                    },
+                    '=' =>
+                    {
+                        let height = stream.next_value();
+
+                        if matches!(&height, TokenTree::Punct(p) if p.as_char() == '#')
+                        {
+                            push_color(vec.last_mut().unwrap(), &stream.next_value().to_string());
+                            continue;
+                        }
+
+                        let height = match height
+                        {
+                            TokenTree::Punct(p) if p.as_char() == '-' =>
+                            {
+                                format!("-{}", stream.next_value())
+                            },
+                            height => height.to_string()
+                        };
+
+                        if height.starts_with("\"#")
+                        {
+                            push_color(vec.last_mut().unwrap(), &height);
+                            continue;
+                        }
+
+                        vec.last_mut().unwrap().height = height
+                            .trim_end_matches("f32")
+                            .parse::<f32>()
+                            .unwrap_or_else(|_| panic!("Invalid color height {height}."))
+                            .into();
+                    },
+                    _ => panic!()
+                }
+
+                continue;
+            }
+
+            let item = item.to_string();
+
+            if end_tags.contains(&item.as_str())
+            {
+                let mut next = stream.next_value();
+
+                if let TokenTree::Group(group) = &next
+                {
+                    steps.push((item.clone(), section_step(group)));
+                    next = stream.next_value();
+                }
+
+                is_column(next);
+                return (vec, item.into());
+            }
+
+            if item.starts_with("\"#")
+            {
+                push_color(vec.last_mut().unwrap(), &item);
+                continue;
+            }
+
+            if item == "alias"
+            {
+                let alias = stream.next_value().to_string();
+                assert!(
+                    matches!(stream.next_value(), TokenTree::Punct(p) if p.as_char() == '='),
+                    "Expected = after alias {alias}."
+                );
+                aliases.push((alias, stream.next_value().to_string()));
+                continue;
+            }
+
+            vec.push(ColorEntry {
+                variants:    vec![item],
+                aliases:     Vec::new(),
+                height:      None,
+                color:       None,
+                light_color: None
+            });
+        }
+
+        (vec, None)
+    }
+
+    /// The height function, the height following the section, and the heights of the variants.
+    type Heights<'a> = (String, f32, Vec<(&'a String, f32)>);
+
+    #[inline]
+    fn generate_height_func<'a, I: Iterator<Item = (&'a ColorEntry, Option<f32>)>>(
+        start: &str,
+        section: &str,
+        range: std::ops::Range<f32>,
+        mut start_height: f32,
+        interval: f32,
+        iter: I,
+        colors: &[&String]
+    ) -> Result<Heights<'a>, TokenStream>
+    {
+        let mut height_func = start.to_string();
+        let mut heights = Vec::<(&String, f32)>::new();
+        let mut entry_heights = Vec::<(&String, f32, bool)>::new();
+        let mut next_height = f32::MIN;
+
+        for (entry, height) in iter
+        {
+            let variant = &entry.variants[0];
+
+            if let Some(height) = height
+            {
+                if !range.contains(&height)
+                {
+                    return Err(format!(
+                        "compile_error!(\"The pinned height {height} of {variant} is outside the \
+                         {section} section.\");"
+                    )
+                    .parse()
+                    .unwrap());
+                }
+
+                next_height = next_height.max(height + interval);
+            }
+
+            let pinned = height.is_some();
+            let height = height.unwrap_or_else(|| {
+                let height = start_height;
+                start_height += interval;
+                height
+            });
+
+            if let Some((other, other_height, _)) = entry_heights
+                .iter()
+                .find(|(_, h, p)| (pinned || *p) && (*h - height).abs() < interval)
+            {
+                return Err(format!(
+                    "compile_error!(\"{variant}, drawn at height {height}, overlaps {other}, \
+                     drawn at height {other_height}, in the {section} section.\");"
+                )
+                .parse()
+                .unwrap());
+            }
+
+            entry_heights.push((variant, height, pinned));
+
+            height_func.push_str(&format!("{} => {height}f32,\n", entry.pattern()));
+
+            for variant in entry.all_variants()
+            {
+                if !heights.iter().any(|(v, _)| *v == variant)
+                {
+                    heights.push((variant, height));
+                }
+            }
+        }
+
+        for color in colors
+            .iter()
+            .filter(|color| !heights.iter().any(|(v, _)| v == *color))
+        {
+            height_func
+                .push_str(&format!("Self::{color} => panic!(\"Invalid color: {color}\"),\n"));
+        }
+
+        height_func.push_str("}\n}");
+        Ok((height_func, start_height.max(next_height), heights))
+    }
+
+    let textures_interval = f32::from(*TEXTURE_HEIGHT_RANGE.end());
+    let mut stream = stream.into_iter();
+
+    let mut key_func = "
+    /// The config file key relative to the drawn color associated with [`Color`].
+    #[inline]
+    #[must_use]
+    pub const fn config_file_key(self) -> &'static str
+    {
+        match self
+        {
+    "
+    .to_string();
+
+    let mut label_func = "
+    /// The text label representing [`Color`] in UI elements.
+    #[inline]
+    #[must_use]
+    pub const fn label(self) -> &'static str
+    {
+        match self
+        {
+    "
+    .to_string();
+
+    let mut tag = stream.next_value().to_string();
+    let mut max_height = None;
+
+    if tag == "max_height"
+    {
+        assert!(
+            matches!(stream.next_value(), TokenTree::Punct(p) if p.as_char() == '='),
+            "Expected = after max_height."
+        );
+        let value = stream.next_value().to_string();
+        max_height = value
+            .trim_end_matches("f32")
+            .parse::<f32>()
+            .unwrap_or_else(|_| panic!("Invalid max height {value}."))
+            .into();
+        is_comma(stream.next_value());
+        tag = stream.next_value().to_string();
+    }
+
+    assert!(tag == "clear");
+    is_column(stream.next_value());
+    let mut aliases = Vec::new();
+    let mut steps = Vec::new();
+    let mut clear =
+        single_entry(extract(&mut stream, &["extensions"], &mut aliases, &mut steps).0, "clear");
+    assert!(steps.is_empty(), "The extensions section cannot have a step.");
+    let (extensions, tag) = extract(&mut stream, &["background", "grid"], &mut aliases, &mut steps);
+    let mut extensions = single_entry(extensions, "extensions");
+    let mut background = if tag.as_deref() == Some("background")
+    {
+        extract(&mut stream, &["grid"], &mut aliases, &mut steps).0
+    }
+    else
+    {
+        Vec::new()
+    };
+    let mut grid = extract(&mut stream, &["entities"], &mut aliases, &mut steps).0;
+    let mut entities = extract(&mut stream, &["ui"], &mut aliases, &mut steps).0;
+    let mut ui = extract(&mut stream, &[], &mut aliases, &mut steps).0;
+    let step = |section: &str, default: f32| {
+        steps
+            .iter()
+            .find_map(|(s, step)| (s == section).then_some(*step))
+            .unwrap_or(default)
+    };
+
+    if let Some(error) = duplicate_error(
+        [
+            ("clear", std::slice::from_ref(&clear)),
+            ("extensions", std::slice::from_ref(&extensions)),
+            ("background", &background),
+            ("grid", &grid),
+            ("entities", &entities),
+            ("ui", &ui)
+        ],
+        &aliases
+    )
+    {
+        return error;
+    }
+
+    for (alias, variant) in &aliases
+    {
+        let mut found = false;
+
+        for entry in [&mut clear, &mut extensions]
+            .into_iter()
+            .chain(&mut background)
+            .chain(&mut grid)
+            .chain(&mut entities)
+            .chain(&mut ui)
+            .filter(|entry| entry.variants.contains(variant))
+        {
+            entry.aliases.push(alias.clone());
+            found = true;
+        }
+
+        assert!(found, "Alias {alias} of unknown color {variant}.");
+    }
+
+    let entries = || {
+        [&clear, &extensions]
+            .into_iter()
+            .chain(&background)
+            .chain(&grid)
+            .chain(&entities)
+            .chain(&ui)
+    };
+
+    let mut variants = Vec::<&String>::new();
+
+    for variant in entries().flat_map(|entry| &entry.variants)
+    {
+        if !variants.contains(&variant)
+        {
+            variants.push(variant);
+        }
+    }
+
+    let mut settings_func = "
+    /// Returns the [`Color`]s listed in the settings along with their labels and config file
+    /// keys, in declaration order.
+    #[inline]
+    #[must_use]
+    pub const fn settings_entries() -> &'static [(Self, &'static str, &'static str)]
+    {
+        &[
+    "
+    .to_string();
+
+    for variant in &variants
+    {
+        settings_func.push_str(&format!(
+            "(Self::{variant}, \"{}\", \"{}\"),\n",
+            label(variant),
+            snake_case(variant)
+        ));
+    }
+
+    settings_func.push_str("]\n}");
+
+    for (item, source) in variants
+        .iter()
+        .map(|variant| (*variant, *variant))
+        .chain(aliases.iter().map(|(alias, variant)| (alias, variant)))
+    {
+        push_key_and_label(item, source, &mut label_func, &mut key_func);
+    }
+
+    for func in [&mut key_func, &mut label_func]
+    {
+        func.push_str("}\n}");
+    }
+
+    let mut from_key_func = "
+    /// Returns the [`Color`] associated with the config file key `key`, if any.
+    #[inline]
+    #[must_use]
+    pub fn from_config_file_key(key: &str) -> Option<Self>
+    {
+        match key
+        {
+    "
+    .to_string();
+
+    let mut light_key_func = "
+    /// The config file key relative to the drawn color associated with [`Color`] in the light
+    /// theme.
+    #[inline]
+    #[must_use]
+    pub const fn light_config_file_key(self) -> &'static str
+    {
+        match self
+        {
+    "
+    .to_string();
+    let mut from_light_key_func = "
+    /// Returns the [`Color`] associated with the light theme config file key `key`, if any.
+    #[inline]
+    #[must_use]
+    pub fn from_light_config_file_key(key: &str) -> Option<Self>
+    {
+        match key
+        {
+    "
+    .to_string();
+
+    for variant in &variants
+    {
+        from_key_func.push_str(&format!("\"{}\" => Some(Self::{variant}),\n", snake_case(variant)));
+        from_light_key_func
+            .push_str(&format!("\"{}_light\" => Some(Self::{variant}),\n", snake_case(variant)));
+    }
+
+    for (item, source) in variants
+        .iter()
+        .map(|variant| (*variant, *variant))
+        .chain(aliases.iter().map(|(alias, variant)| (alias, variant)))
+    {
+        light_key_func.push_str(&format!("Self::{item} => \"{}_light\",\n", snake_case(source)));
+    }
+
+    light_key_func.push_str("}\n}");
+    from_light_key_func.push_str("_ => None\n}\n}");
+
+    from_key_func.push_str(
+        "_ => None\n}\n}
+
+    /// Returns the config file line storing `color`, as RGBA bytes, as the value of the config
+    /// file key of [`Color`].
+    #[inline]
+    #[must_use]
+    pub fn to_config_string(self, color: [u8; 4]) -> String
+    {
+        let [r, g, b, a] = color;
+        format!(\"{} = #{r:02x}{g:02x}{b:02x}{a:02x}\", self.config_file_key())
+    }
+
+    /// Parses a config file line in the `key = #rrggbb` or `key = #rrggbbaa` format, returning
+    /// the associated [`Color`] and the RGBA bytes of the value, if valid.
+    #[inline]
+    #[must_use]
+    pub fn parse_config_entry(line: &str) -> Option<(Self, [u8; 4])>
+    {
+        let (key, value) = line.split_once('=')?;
+        let color = Self::from_config_file_key(key.trim())?;
+        let hex = value.trim().strip_prefix('#')?;
+
+        if !matches!(hex.len(), 6 | 8) || !hex.bytes().all(|b| b.is_ascii_hexdigit())
+        {
+            return None;
+        }
+
+        let mut rgba = [u8::MAX; 4];
+
+        for (i, c) in rgba.iter_mut().enumerate().take(hex.len() / 2)
+        {
+            *c = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).ok()?;
+        }
+
+        Some((color, rgba))
+    }"
+    );
+
+    let section_iter_funcs = [
+        (
+            "background_colors",
+            "BACKGROUND_COLORS",
+            "is_background",
+            "background",
+            &background
+        ),
+        ("grid_colors", "GRID_COLORS", "is_grid", "grid", &grid),
+        ("entity_colors", "ENTITY_COLORS", "is_entity", "entities", &entities),
+        ("ui_colors", "UI_COLORS", "is_ui", "ui", &ui)
+    ]
+    .into_iter()
+    .map(|(iter, count, predicate, section, entries)| {
+        format!(
+            "{}\n{}",
+            section_iter_func(iter, count, section, entries),
+            section_predicate_func(predicate, section, entries)
+        )
+    })
+    .collect::<Vec<_>>()
+    .join("\n");
+
+    let mut category_func = "
+    /// Returns the [`ColorCategory`] of the section [`Color`] belongs to. Colors listed in more
+    /// than one section belong to the first one.
+    #[inline]
+    #[must_use]
+    pub const fn category(self) -> ColorCategory
+    {
+        match self
+        {
+    "
+    .to_string();
+    let mut categorized = Vec::new();
+
+    for (category, entries) in [
+        ("Clear", std::slice::from_ref(&clear)),
+        ("Extensions", std::slice::from_ref(&extensions)),
+        ("Background", &background[..]),
+        ("Grid", &grid[..]),
+        ("Entities", &entities[..]),
+        ("Ui", &ui[..])
+    ]
+    {
+        for variant in entries.iter().flat_map(ColorEntry::all_variants)
+        {
+            if !categorized.contains(&variant)
+            {
+                category_func.push_str(&format!("Self::{variant} => ColorCategory::{category},\n"));
+                categorized.push(variant);
+            }
+        }
+    }
+
+    category_func.push_str("}\n}");
+
+    let collect_defaults = |color: fn(&ColorEntry) -> Option<[u8; 4]>| {
+        let mut defaults = Vec::<(&String, [u8; 4])>::new();
+
+        for (entry, c) in entries().filter_map(|entry| color(entry).map(|c| (entry, c)))
+        {
+            for variant in entry.all_variants()
+            {
+                match defaults.iter().find(|(v, _)| *v == variant)
+                {
+                    Some((_, color)) =>
+                    {
+                        assert!(*color == c, "Conflicting default colors for {variant}.");
+                    },
+                    None => defaults.push((variant, c))
+                }
+            }
+        }
+
+        defaults
+    };
+    let defaults = collect_defaults(|entry| entry.color);
+    let light_defaults = collect_defaults(|entry| entry.light_color);
+
+    let default_color_func = if defaults.is_empty()
+    {
+        String::new()
+    }
+    else
+    {
+        let undefaulted = variants
+            .iter()
+            .filter(|variant| !defaults.iter().any(|(v, _)| v == *variant))
+            .collect::<Vec<_>>();
+        assert!(undefaulted.is_empty(), "Missing default colors for {undefaulted:?}.");
+
+        let mut func = "
+    /// The default color associated with [`Color`], as RGBA bytes.
+    #[inline]
+    #[must_use]
+    pub const fn default_color(self) -> [u8; 4]
+    {
+        match self
+        {
+    "
+        .to_string();
+
+        for (variant, [r, g, b, a]) in &defaults
+        {
+            func.push_str(&format!("Self::{variant} => [{r}, {g}, {b}, {a}],\n"));
+        }
+
+        func.push_str("}\n}");
+
+        if !light_defaults.is_empty()
+        {
+            func.push_str(
+                "
+    /// The default color associated with [`Color`] in the light theme, as RGBA bytes. Equal to
+    /// the one returned by `default_color` unless specified.
+    #[inline]
+    #[must_use]
+    pub const fn default_light_color(self) -> [u8; 4]
+    {
+        match self
+        {
+    "
+            );
+
+            for (variant, color) in &defaults
+            {
+                let [r, g, b, a] = light_defaults
+                    .iter()
+                    .find_map(|(v, c)| (v == variant).then_some(*c))
+                    .unwrap_or(*color);
+                func.push_str(&format!("Self::{variant} => [{r}, {g}, {b}, {a}],\n"));
+            }
+
+            func.push_str("}\n}");
+        }
+
+        func
+    };
+
+    let colors = variants
+        .iter()
+        .copied()
+        .chain(aliases.iter().map(|(alias, _)| alias))
+        .collect::<Vec<_>>();
+
+    let entities_step = step("entities", textures_interval + 1f32);
+    let selected_offset = (entities_step / 2f32).min(0.5);
+
+    let (height_func, clip_height, entity_heights) = match generate_height_func(
+        "
+    /// The height at which map elements colored with a certain [`Color`] should be drawn.
+    #[inline]
+    #[must_use]
+    pub const fn entity_height(self) -> f32
+    {
+        match self
+        {",
+        "entities",
+        1f32..f32::INFINITY,
+        1f32,
+        entities_step,
+        entities.iter().map(|entry| (entry, entry.height)),
+        &colors
+    )
+    {
+        Ok(value) => value,
+        Err(error) => return error
+    };
+
+    let mut from_height_func = "
+    /// Returns the [`Color`] of the entities section drawn at `height`, if any.
+    #[inline]
+    #[must_use]
+    pub const fn from_entity_height(height: f32) -> Option<Self>
+    {
+    "
+    .to_string();
+    let mut found_heights = Vec::new();
+
+    for (variant, height) in &entity_heights
+    {
+        if !found_heights.contains(height)
+        {
+            from_height_func.push_str(&format!(
+                "if height == {height}f32 {{ return Some(Self::{variant}); }}\n"
+            ));
+            found_heights.push(*height);
+        }
+    }
+
+    from_height_func.push_str("None\n}");
+
+    let (line_height_func, thing_angle_height, line_heights) = match generate_height_func(
+        "
+    /// The draw height of the lines.
+    #[inline]
+    #[must_use]
+    pub const fn line_height(self) -> f32
+    {
+        match self
+        {
+    ",
+        "grid",
+        clip_height + 1f32..f32::INFINITY,
+        clip_height + 1f32,
+        step("grid", 1f32),
+        grid.iter().map(|entry| (entry, entry.height)).chain(
+            Some(&extensions)
+                .into_iter()
+                .chain(&entities)
+                .chain(&ui)
+                .map(|entry| (entry, None))
+        ),
+        &colors
+    )
+    {
+        Ok(value) => value,
+        Err(error) => return error
+    };
+
+    let (background_height_func, _, background_heights) = match generate_height_func(
+        "
+    /// The draw height of the background layers, beneath the entities.
+    #[inline]
+    #[must_use]
+    pub const fn background_height(self) -> f32
+    {
+        match self
+        {
+    ",
+        "background",
+        f32::MIN..0f32,
+        -(background.iter().filter(|entry| entry.height.is_none()).count() as f32) *
+            step("background", 1f32),
+        step("background", 1f32),
+        background.iter().map(|entry| (entry, entry.height)),
+        &colors
+    )
+    {
+        Ok(value) => value,
+        Err(error) => return error
+    };
+    let background_height_func =
+        if background.is_empty() { String::new() } else { background_height_func };
+
+    let mut height_table = "
+    /// Every [`Color`] but the clear one along with the height it is drawn at, the background
+    /// height for the colors of the background section, the entity height for the colors of the
+    /// entities section, and the line height for the others, plus the line height of the colors
+    /// of the entities section.
+    pub const HEIGHT_TABLE: &'static [(Self, f32, Option<f32>)] = &[
+    "
+    .to_string();
+
+    for (variant, height) in &background_heights
+    {
+        height_table.push_str(&format!("(Self::{variant}, {height}f32, None),\n"));
+    }
+
+    for (variant, height) in &entity_heights
+    {
+        let line_height = line_heights
+            .iter()
+            .find_map(|(v, h)| (v == variant).then_some(*h))
+            .unwrap();
+        height_table
+            .push_str(&format!("(Self::{variant}, {height}f32, Some({line_height}f32)),\n"));
+    }
+
+    for (variant, height) in line_heights.iter().filter(|(variant, _)| {
+        !background_heights
+            .iter()
+            .chain(&entity_heights)
+            .any(|(v, _)| v == variant)
+    })
+    {
+        height_table.push_str(&format!("(Self::{variant}, {height}f32, None),\n"));
+    }
+
+    height_table.push_str("];");
+
+    let (square_hgl_height_func, _, square_hgl_heights) = match generate_height_func(
+        "
+    /// The draw height of the square highlights.
+    #[inline]
+    #[must_use]
+    pub const fn square_hgl_height(self) -> f32
+    {
+        match self
+        {
+    ",
+        "ui",
+        thing_angle_height + 2f32..f32::INFINITY,
+        thing_angle_height + 2f32,
+        step("ui", 1f32),
+        ui.iter().map(|entry| (entry, entry.height)),
+        &colors
+    )
+    {
+        Ok(value) => value,
+        Err(error) => return error
+    };
+
+    if let Some(max_height) = max_height
+    {
+        let height = background_heights
+            .iter()
+            .chain(&line_heights)
+            .chain(&square_hgl_heights)
+            .map(|(_, height)| *height)
+            .chain(entity_heights.iter().map(|(_, height)| height + selected_offset))
+            .chain([clip_height, thing_angle_height + 1f32])
+            .fold(f32::MIN, f32::max);
+
+        if height > max_height
+        {
+            return format!(
+                "compile_error!(\"The color heights reach {height}, exceeding the max_height of \
+                 {max_height}.\");"
+            )
+            .parse()
+            .unwrap();
+        }
+    }
+
+    format!(
+        "
+    {background_height_func}
+
+    {height_func}
+
+    {from_height_func}
+
+    /// The draw height of an untextured polygon.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::drawer) const fn polygon_height(self) -> f32 {{ self.entity_height() - 1f32 \
+         }}
+
+    /// The draw height of the selected or highlighted map elements colored with a certain
+    /// [`Color`], above the one returned by `entity_height` and below the one of the following
+    /// color.
+    #[inline]
+    #[must_use]
+    pub const fn selected_height(self) -> f32 {{ self.entity_height() + {selected_offset}f32 }}
+
+    /// The draw height of the clip overlay.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::drawer) const fn clip_height() -> f32 {{ {clip_height}f32 }}
+
+    {line_height_func}
+
+    /// The draw height of the thing angle indicator.
+    #[inline]
+    #[must_use]
+    pub(in crate::map::drawer) const fn thing_angle_indicator_height() -> [f32; 2]
+    {{
+        [
+            {thing_angle_height}f32,
+            {thing_angle_height}f32 + 1f32
+        ]
+    }}
+
+    {square_hgl_height_func}
+
+    {height_table}
+
+    {key_func}
+
+    {from_key_func}
+
+    {light_key_func}
+
+    {from_light_key_func}
+
+    {label_func}
+
+    {settings_func}
+
+    {section_iter_funcs}
+
+    {category_func}
+
+    {default_color_func}"
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Generates the `ColorCategory` enum returned by the `category` method created by
+/// [`color_enum`], with one variant for each section of the macro.
+/// The input is the visibility of the enum.
+/// # Examples
+/// ```ignore
+/// color_category!(pub(crate));
+/// ```
+#[proc_macro]
+pub fn color_category(stream: TokenStream) -> TokenStream
+{
+    format!(
+        "
+    /// The section of `color_enum` a color belongs to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    {stream} enum ColorCategory
+    {{
+        /// The clear color.
+        Clear,
+        /// The map extensions color.
+        Extensions,
+        /// The background colors.
+        Background,
+        /// The grid colors.
+        Grid,
+        /// The map entities colors.
+        Entities,
+        /// The UI colors.
+        Ui
+    }}"
+    )
+    .parse()
+    .unwrap()
+}
+
+//=======================================================================//
+
+/// Returns the variants of the enum named `name`, either listed in `tools` as `[Variant, ...]` or
+/// read from the file at the path `tools`, relative to the crate root, which defaults to
+/// `src/map/editor/state/core/tool.rs`. In the latter case the path of the file is returned as
+/// well.
+/// # Panics
+/// Panics if the file cannot be read or does not contain the enum.
+#[must_use]
+fn tool_variants(tools: Option<TokenTree>, name: &str)
+    -> (Vec<Variant>, Option<std::path::PathBuf>)
+{
+    let path = match tools
+    {
+        Some(TokenTree::Group(group)) => return (enum_variants(TokenTree::Group(group)), None),
+        Some(TokenTree::Literal(path)) => path.to_string().trim_matches('"').to_string(),
+        None => "src/map/editor/state/core/tool.rs".to_string(),
+        Some(item) => panic!("Invalid {name} argument {item}.")
+    };
+
+    let path = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join(path);
+    let file = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Could not read {}.", path.display()));
+
+    match enum_body(file.parse::<TokenStream>().unwrap(), name)
+    {
+        Some(body) => (enum_variants(body), path.into()),
+        None => panic!("The {name} enum is not declared in {}.", path.display())
+    }
+}
+
+//=======================================================================//
+
+/// Returns the body of the enum named `name` declared in `stream`, also looking inside the
+/// brace delimited groups, such as inline modules. Comments are not part of `stream`, so they
+/// cannot be mistaken for code.
+#[must_use]
+fn enum_body(stream: TokenStream, name: &str) -> Option<TokenTree>
+{
+    let mut iter = stream.into_iter();
+
+    while let Some(item) = iter.next()
+    {
+        match item
+        {
+            TokenTree::Ident(ident)
+                if ident.to_string() == "enum" && iter.next_value().to_string() == name =>
+            {
+                return iter.find(
+                    |item| matches!(item, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace)
+                );
+            },
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace =>
+            {
+                if let Some(body) = enum_body(group.stream(), name)
+                {
+                    return body.into();
+                }
+            },
+            _ => ()
+        }
+    }
+
+    None
+}
+
+//=======================================================================//
+
+/// Returns the escaped text of the doc comments among `attributes`, joined by spaces.
+#[inline]
+#[must_use]
+fn doc_description(attributes: &[Group]) -> String
+{
+    attributes
+        .iter()
+        .filter_map(|attribute| {
+            let mut iter = attribute.stream().into_iter();
+
+            if iter.next()?.to_string() != "doc" || iter.next()?.to_string() != "="
+            {
+                return None;
+            }
+
+            let literal = iter.next()?.to_string();
+            literal
+                .strip_prefix('"')
+                .and_then(|literal| literal.strip_suffix('"'))
+                .map(|literal| literal.trim().to_string())
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+//=======================================================================//
+
+/// Returns the `#[cfg]` attributes of `variant`.
+#[inline]
+#[must_use]
+fn variant_cfg(variant: &Variant) -> String
+{
+    variant
+        .attributes
+        .iter()
+        .filter(|attribute| find_attribute(std::slice::from_ref(attribute), "cfg").is_some())
+        .map(|attribute| format!("#{attribute}\n"))
+        .collect()
+}
+
+//=======================================================================//
+
+/// Generates the `Bind` enum plus the `config_file_key()` and `label()` methods.
+/// The binds associated with the tools are added for each variant of the `Tool` enum, which is
+/// read from `src/map/editor/state/core/tool.rs` unless the input contains either
+/// `tools = "path/to/file.rs"` or `tools = [Variant, ...]`. The derive of [`ToolEnum`] checks
+/// that a bind was generated for every variant of the `Tool` enum.
+/// The `#[cfg]` attributes of the `Tool` variants, and of the binds in the input, are carried
+/// over onto the generated variants and match arms, so feature gated tools only have a bind
+/// when their feature is enabled.
+/// The binds in the input can be given a default key with `Bind = KeyCode`, in which case a
+/// `default_keycode` method is generated, requiring `KeyCode` to be in scope.
+/// Binds can also default to a chord, a sequence of up to `MAX_CHORD_LEN` keys such as
+/// `Bind = KeyG > KeyX`, in which case a `default_chord` method is generated as well. Chords are
+/// written in the config file with their keys separated by `>`, through `chord_to_config_string`
+/// and `parse_chord`.
+/// The generated `conflicts` method requires `BindsKeyCodes` to be in scope.
+/// The binds can be grouped by preceding them with `category:` headers, in which case a
+/// `BindCategory` enum is generated along with a `category` method, and the binds of the tools
+/// belong to the `Tools` category.
+/// The generated `description` method returns the string literal following a bind, or its doc
+/// comments otherwise. The descriptions of the tool binds are the doc comments of the `Tool`
+/// variants.
+/// The `ALL` constant contains every `Bind`, and `from_config_file_key` returns the `Bind`
+/// associated with a config file key, which is also how `FromStr` parses it, returning a
+/// `BindFromStrError` on failure.
+/// The subtools can be made rebindable with either `subtools = "path/to/file.rs"` or
+/// `subtools = [Variant, ...]`, generating a `SubTool`-prefixed bind for each variant of the
+/// `SubTool` enum, belonging to the `Subtools` category. Their labels are the ones of the
+/// subtools, and their config file keys are prefixed by `subtool_`. The derive of [`SubToolEnum`]
+/// then returns them through `rebindable_bind`.
+/// The labels can be translated with `labels = [It = [Bind = "label", ...], ...]`, in which case
+/// `label` takes the `Language` to return the label in, requiring `Language` to be in scope. The
+/// binds without a translation fall back to the label returned by `default_label`.
+/// With `settings_table = true` a `show_settings_table` method is generated, showing the binds
+/// in an `egui::Grid`, grouped by category if any, each row containing the label, the current key
+/// and a button to rebind it. It requires `egui` to be in scope.
+/// The keys reserved to the controls that cannot be rebound can be listed with
+/// `reserved = [KeyCode, ...]`, in which case compilation fails if a bind defaults to one of them,
+/// and they are stored in the generated `RESERVED_KEYCODES` constant.
+/// Applications extending the editor can provide their own binds with `external = [Bind, ...]`,
+/// which are placed after all the others, in the `EDITOR_LEN..SIZE` index range returned by
+/// `EXTERNAL`, and belong to the `External` category. The indexes of the binds of the editor are
+/// therefore the same whether or not external binds are provided.
+/// # Examples
+/// ```ignore
+/// bind_enum!(Left = ArrowLeft, Right = ArrowRight, tools = [Square, Triangle, Circle]);
+/// ```
+/// # Panics
+/// Panic if the file containing the `Tool` enum cannot be read.
+#[proc_macro]
+pub fn bind_enum(input: TokenStream) -> TokenStream
+{
+    let mut tools = None;
+    let mut subtools = None;
+    let mut labels = None;
+    let mut settings_table = false;
+    let mut reserved = None;
+    let mut external = Vec::new();
+    let mut binds = Vec::new();
+    let mut categories = Vec::<String>::new();
+
+    for mut segment in split_at_commas(input)
+    {
+        match segment.as_slice()
+        {
+            [TokenTree::Ident(key), TokenTree::Punct(p), value]
+                if key.to_string() == "tools" && p.as_char() == '=' =>
+            {
+                tools = value.clone().into();
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), value]
+                if key.to_string() == "subtools" && p.as_char() == '=' =>
+            {
+                subtools = value.clone().into();
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), value]
+                if key.to_string() == "settings_table" && p.as_char() == '=' =>
+            {
+                settings_table = bool_option("settings_table", &value.to_string());
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), TokenTree::Group(group)]
+                if key.to_string() == "labels" && p.as_char() == '=' =>
+            {
+                labels = group.stream().into();
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), TokenTree::Group(group)]
+                if key.to_string() == "reserved" && p.as_char() == '=' =>
+            {
+                reserved = group
+                    .stream()
+                    .into_iter()
+                    .filter_map(|item| {
+                        match item
+                        {
+                            TokenTree::Ident(ident) => ident.to_string().into(),
+                            TokenTree::Punct(p) if p.as_char() == ',' => None,
+                            item => panic!("Invalid reserved key {item}.")
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .into();
+            },
+            [TokenTree::Ident(key), TokenTree::Punct(p), TokenTree::Group(group)]
+                if key.to_string() == "external" && p.as_char() == '=' =>
+            {
+                external = split_at_commas(group.stream());
+            },
+            _ =>
+            {
+                if let [TokenTree::Ident(name), TokenTree::Punct(p), ..] = segment.as_slice()
+                {
+                    if p.as_char() == ':'
+                    {
+                        let category = camel_case(&name.to_string());
+
+                        if !categories.contains(&category)
+                        {
+                            categories.push(category);
+                        }
+
+                        segment.drain(..2);
+
+                        if segment.is_empty()
+                        {
+                            continue;
+                        }
+                    }
+                }
+
+                binds.push(BindEntry::new(segment, categories.last().cloned()));
+            }
+        }
+    }
+
+    const EQ: &str = "
+        const fn eq(a: &[u8], b: &[u8]) -> bool
+        {
+            if a.len() != b.len()
+            {
+                return false;
+            }
+
+            let mut i = 0;
+
+            while i < a.len()
+            {
+                if a[i] != b[i]
+                {
+                    return false;
+                }
+
+                i += 1;
+            }
+
+            true
+        }
+
+        let name = name.as_bytes();
+    ";
+
+    let (tools, path) = tool_variants(tools, "Tool");
+    let mut tool_bind_func = format!(
+        "
+    /// Whether a `Bind` was generated for the `Tool` variant named `name`.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    pub const fn tool_bind_exists(name: &str) -> bool
+    {{
+        {EQ}"
+    );
+
+    for variant in tools
+    {
+        let cfg = variant_cfg(&variant);
+        let ident = variant.ident.to_string();
+        tool_bind_func.push_str(&format!("{cfg}if eq(name, b\"{ident}\") {{ return true; }}\n"));
+        binds.push(BindEntry {
+            attributes: cfg.clone(),
+            cfg,
+            label: label(&ident),
+            key: snake_case(&ident),
+            ident,
+            keycodes: Vec::new(),
+            description: doc_description(&variant.attributes),
+            category: (!categories.is_empty()).then(|| "Tools".to_string())
+        });
+    }
+
+    let mut subtool_bind_func = format!(
+        "
+    /// Returns the `Bind` generated for the `SubTool` variant named `name`, if any.
+    #[doc(hidden)]
+    #[inline]
+    #[must_use]
+    pub const fn subtool_bind(name: &str) -> Option<Self>
+    {{
+        {EQ}"
+    );
+
+    let (subtools, subtools_path) =
+        subtools.map_or((Vec::new(), None), |subtools| tool_variants(subtools.into(), "SubTool"));
+
+    for variant in subtools
+    {
+        let cfg = variant_cfg(&variant);
+        let subtool = variant.ident.to_string();
+        let ident = format!("SubTool{subtool}");
+        subtool_bind_func.push_str(&format!(
+            "{cfg}if eq(name, b\"{subtool}\") {{ return Some(Self::{ident}); }}\n"
+        ));
+        binds.push(BindEntry {
+            attributes: cfg.clone(),
+            cfg,
+            ident,
+            label: label(&subtool),
+            key: format!("subtool_{}", snake_case(&subtool)),
+            keycodes: Vec::new(),
+            description: doc_description(&variant.attributes),
+            category: (!categories.is_empty()).then(|| "Subtools".to_string())
+        });
+    }
+
+    let external_len = external.len();
+    let external = external.into_iter().map(|segment| {
+        let bind =
+            BindEntry::new(segment, (!categories.is_empty()).then(|| "External".to_string()));
+        assert!(bind.cfg.is_empty(), "External bind {} cannot be cfg-gated.", bind.ident);
+        bind
+    });
+    binds.extend(external);
+
+    assert!(
+        categories.is_empty() || binds.iter().all(|bind| bind.category.is_some()),
+        "The binds preceding the first category have no category."
+    );
+
+    let reserved_keycodes = match reserved
+    {
+        Some(reserved) =>
+        {
+            let errors = binds
+                .iter()
+                .flat_map(|bind| {
+                    bind.keycodes
+                        .iter()
+                        .filter(|key| reserved.contains(key))
+                        .map(move |key| (&bind.ident, key))
+                })
+                .map(|(ident, key)| {
+                    format!("compile_error!(\"Bind {ident} defaults to the reserved key {key}.\");")
+                })
+                .collect::<String>();
+
+            if !errors.is_empty()
+            {
+                return errors.parse().unwrap();
+            }
+
+            format!(
+                "
+            /// The keys reserved to the controls that cannot be rebound.
+            pub const RESERVED_KEYCODES: [KeyCode; {}] = [{}];",
+                reserved.len(),
+                reserved
+                    .iter()
+                    .map(|key| format!("KeyCode::{key}, "))
+                    .collect::<String>()
+            )
+        },
+        None => String::new()
+    };
+
+    let category_items = if categories.is_empty()
+    {
+        String::new()
+    }
+    else
+    {
+        for category in ["Tools", "Subtools", "External"]
+        {
+            if !categories.iter().any(|c| c == category) &&
+                binds.iter().any(|bind| bind.category.as_deref() == Some(category))
+            {
+                categories.push(category.to_string());
+            }
+        }
+
+        let mut category_func = "
+    /// Returns the category of this `Bind`.
+    #[inline]
+    #[must_use]
+    pub const fn category(self) -> BindCategory
+    {
+        match self
+        {\n"
+        .to_string();
+
+        for BindEntry {
+            cfg,
+            ident,
+            category,
+            ..
+        } in &binds
+        {
+            category_func.push_str(&format!(
+                "{cfg}Self::{ident} => BindCategory::{},\n",
+                category.as_ref().unwrap()
+            ));
+        }
+
+        category_func.push_str("}\n}");
+
+        format!(
+            "
+        /// The categories grouping the binds in the settings.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, EnumSize)]
+        pub enum BindCategory
+        {{
+            {}
+        }}
+
+        impl BindCategory
+        {{
+            /// Returns the text representing this `BindCategory` in UI elements.
+            #[inline]
+            #[must_use]
+            pub const fn label(self) -> &'static str
+            {{
+                match self
+                {{
+                    {}
+                }}
+            }}
+        }}
+
+        impl Bind
+        {{
+            {category_func}
+        }}",
+            categories.iter().map(|c| format!("{c},")).collect::<String>(),
+            categories
+                .iter()
+                .map(|c| format!("Self::{c} => \"{}\",", label(c)))
+                .collect::<String>()
+        )
+    };
 
-    let (square_hgl_height_func, _) = generate_height_func(
-        "
-    /// The draw height of the square highlights.
+    tool_bind_func.push_str("false\n}");
+    subtool_bind_func.push_str("None\n}");
+
+    let mut key_func = "
+    /// Returns the string key used in the config file associated with this `Bind`. 
     #[inline]
     #[must_use]
-    pub fn square_hgl_height(self) -> f32
+    pub(in crate::config::controls) const fn config_file_key(self) -> &'static str
     {
         match self
-        {
-    ",
-        thing_angle_height + 2f32,
-        1f32,
-        ui.iter().map(String::as_str)
-    );
+        {\n"
+    .to_string();
 
-    format!(
+    let mut label_func = format!(
         "
-    {height_func}
-
-    /// The draw height of an untextured polygon.
+    /// Returns the text representing this `Bind` in UI elements{}.
     #[inline]
     #[must_use]
-    pub(in crate::map::drawer) fn polygon_height(self) -> f32 {{ self.entity_height() - 1f32 }}
+    pub const fn {}(self) -> &'static str
+    {{
+        match self
+        {{\n",
+        if labels.is_some() { " when no translation is available" } else { "" },
+        if labels.is_some() { "default_label" } else { "label" }
+    );
 
-    /// The draw height of the clip overlay.
+    let mut body = String::new();
+    let mut all = String::new();
+
+    let mut keycode_func = "
+    /// Returns the default `KeyCode` associated with this `Bind`, if any and it is not a chord.
     #[inline]
     #[must_use]
-    pub(in crate::map::drawer) const fn clip_height() -> f32 {{ {clip_height}f32 }}
-
-    {line_height_func}
+    pub const fn default_keycode(self) -> Option<KeyCode>
+    {
+        match self
+        {\n"
+    .to_string();
 
-    /// The draw height of the thing angle indicator.
+    let mut chord_func = "
+    /// Returns the default sequence of `KeyCode`s associated with this `Bind`, if any.
     #[inline]
     #[must_use]
-    pub(in crate::map::drawer) fn thing_angle_indicator_height() -> [f32; 2]
-    {{
-        [
-            {thing_angle_height}f32,
-            {thing_angle_height}f32 + 1f32
-        ]
-    }}
-
-    {square_hgl_height_func}
-
-    {key_func}
-
-    {label_func}"
-    )
-    .parse()
-    .unwrap()
-}
-
-//=======================================================================//
-
-/// Generates the `Bind` enum plus the `config_file_key()` and `label()` methods.
-/// # Panics
-/// Panic if the file containing the `Tool` enum is not at the required location.
-#[proc_macro]
-pub fn bind_enum(input: TokenStream) -> TokenStream
-{
-    let mut binds = "{".to_string();
-    binds.push_str(&input.to_string());
-    binds.push(',');
-
-    let mut path = std::env::current_dir().unwrap();
-    path.push("src/map/editor/state/core/tool.rs");
-
-    let mut lines = BufReader::new(File::open(path).unwrap()).lines().map(Result::unwrap);
-    lines.find(|line| line.ends_with("enum Tool"));
-    lines.next();
-
-    for line in lines
+    pub const fn default_chord(self) -> Option<&'static [KeyCode]>
     {
-        binds.push_str(&line);
-        binds.push('\n');
-
-        if line.contains('}')
-        {
-            break;
-        }
-    }
-
-    let mut iter = binds.clone().parse::<TokenStream>().unwrap().into_iter();
+        match self
+        {\n"
+    .to_string();
 
-    let mut key_func = "
-    /// Returns the string key used in the config file associated with this `Bind`. 
+    let mut description_func = "
+    /// Returns the text describing the action performed by this `Bind`.
     #[inline]
     #[must_use]
-    pub(in crate::config::controls) const fn config_file_key(self) -> &'static str
+    pub const fn description(self) -> &'static str
     {
         match self
         {\n"
     .to_string();
 
-    let mut label_func = "
-    /// Returns the text representing this `Bind` in UI elements.
+    let mut from_key_func = "
+    /// Returns the `Bind` associated with the config file key `key`, if any.
     #[inline]
     #[must_use]
-    pub const fn label(self) -> &'static str
+    pub fn from_config_file_key(key: &str) -> Option<Self>
     {
-        match self
+        match key
         {\n"
     .to_string();
 
-    for item in match_or_panic!(iter.next_value(), TokenTree::Group(g), g).stream()
+    for BindEntry {
+        attributes,
+        cfg,
+        ident,
+        label,
+        key,
+        keycodes,
+        description,
+        ..
+    } in &binds
     {
-        if let TokenTree::Ident(ident) = item
+        description_func.push_str(&format!("{cfg}Self::{ident} => \"{description}\",\n"));
+        body.push_str(&format!("{attributes}{ident},\n"));
+        all.push_str(&format!("{cfg}Self::{ident},\n"));
+        from_key_func.push_str(&format!("{cfg}\"{key}\" => Some(Self::{ident}),\n"));
+        label_func.push_str(&format!("{cfg}Self::{ident} => \"{label}\",\n"));
+        key_func.push_str(&format!("{cfg}Self::{ident} => \"{key}\",\n"));
+        keycode_func.push_str(&format!("{cfg}Self::{ident} => {},\n", match keycodes.as_slice()
         {
-            let ident = ident.to_string();
-            let mut chars = ident.chars();
-            let mut value = chars.next_value().to_string();
-
-            for ch in chars
+            [key] => format!("Some(KeyCode::{key})"),
+            _ => "None".to_string()
+        }));
+        chord_func.push_str(&format!(
+            "{cfg}Self::{ident} => {},\n",
+            if keycodes.is_empty()
             {
-                if ch.is_ascii_uppercase()
-                {
-                    value.push(' ');
-                }
-
-                value.push(ch);
+                "None".to_string()
             }
+            else
+            {
+                format!(
+                    "Some(&[{}])",
+                    keycodes
+                        .iter()
+                        .map(|key| format!("KeyCode::{key}, "))
+                        .collect::<String>()
+                )
+            }
+        ));
+    }
 
-            label_func.push_str(&format!("Self::{ident} => \"{value}\",\n"));
+    keycode_func.push_str("}\n}");
+    chord_func.push_str("}\n}");
+    from_key_func.push_str("_ => None\n}\n}");
+    description_func.push_str("}\n}");
 
-            value = value.to_ascii_lowercase().replace(' ', "_");
-            key_func.push_str(&format!("Self::{ident} => \"{value}\",\n"));
-        }
+    let keycode_func = if binds.iter().any(|bind| !bind.keycodes.is_empty())
+    {
+        keycode_func
+    }
+    else
+    {
+        String::new()
+    };
+
+    let chord_func = if binds.iter().any(|bind| bind.keycodes.len() > 1)
+    {
+        chord_func
     }
+    else
+    {
+        String::new()
+    };
 
     for func in [&mut key_func, &mut label_func]
     {
         func.push_str("}\n}");
     }
 
+    let settings_table_func = if settings_table
+    {
+        let (language_arg, language) =
+            if labels.is_some() { ("language: Language,", "language") } else { ("", "") };
+        let rows = if categories.is_empty()
+        {
+            "for bind in Self::iter() { row(ui, bind); }"
+        }
+        else
+        {
+            "for category in BindCategory::iter()
+            {
+                ui.label(egui::RichText::new(category.label()).strong());
+                ui.end_row();
+
+                for bind in Self::iter().filter(|bind| bind.category() == category)
+                {
+                    row(ui, bind);
+                }
+            }"
+        };
+
+        format!(
+            "
+        /// Shows the `Bind`s in a grid, each row containing the label, the key returned by `key`,
+        /// if any, and a button calling `rebind` when clicked.
+        #[inline]
+        pub fn show_settings_table<K: std::fmt::Display>(
+            ui: &mut egui::Ui,
+            {language_arg}
+            mut key: impl FnMut(Self) -> Option<K>,
+            mut rebind: impl FnMut(Self)
+        )
+        {{
+            egui::Grid::new(\"binds_settings_table\").striped(true).show(ui, |ui| {{
+                let mut row = |ui: &mut egui::Ui, bind: Self| {{
+                    let label = bind.label({language});
+                    let description = bind.description();
+
+                    if description.is_empty()
+                    {{
+                        ui.label(label);
+                    }}
+                    else
+                    {{
+                        ui.label(label).on_hover_text(description);
+                    }}
+
+                    ui.label(key(bind).map_or_else(String::new, |key| key.to_string()));
+
+                    if ui.button(\"Rebind\").clicked()
+                    {{
+                        rebind(bind);
+                    }}
+
+                    ui.end_row();
+                }};
+
+                {rows}
+            }});
+        }}"
+        )
+    }
+    else
+    {
+        String::new()
+    };
+
+    let localized_label_func = labels.map_or_else(String::new, |labels| {
+        let mut func = "
+        /// Returns the text representing this `Bind` in UI elements in `language`.
+        #[inline]
+        #[must_use]
+        pub const fn label(self, language: Language) -> &'static str
+        {
+            match (language, self)
+            {\n"
+        .to_string();
+
+        for table in split_at_commas(labels)
+        {
+            let (language, table) = match table.as_slice()
+            {
+                [TokenTree::Ident(language), TokenTree::Punct(p), TokenTree::Group(table)]
+                    if p.as_char() == '=' =>
+                {
+                    (language.to_string(), table.stream())
+                },
+                _ => panic!("Invalid labels table, the expected format is Language = [...].")
+            };
+
+            for entry in split_at_commas(table)
+            {
+                let (ident, text) = match entry.as_slice()
+                {
+                    [TokenTree::Ident(ident), TokenTree::Punct(p), TokenTree::Literal(text)]
+                        if p.as_char() == '=' =>
+                    {
+                        (ident.to_string(), text.to_string())
+                    },
+                    _ =>
+                    {
+                        panic!("Invalid {language} label, the expected format is Bind = \"label\".")
+                    }
+                };
+
+                let bind = binds
+                    .iter()
+                    .find(|bind| bind.ident == ident)
+                    .unwrap_or_else(|| panic!("Unknown bind {ident} in the {language} labels."));
+                func.push_str(&format!(
+                    "{}(Language::{language}, Self::{ident}) => {text},\n",
+                    bind.cfg
+                ));
+            }
+        }
+
+        func.push_str("_ => self.default_label()\n}\n}");
+        func
+    });
+
+    let tracking = path
+        .into_iter()
+        .chain(subtools_path)
+        .map(|path| format!("const _: &str = include_str!({:?});", path.display().to_string()))
+        .collect::<String>();
+
     format!(
         "
+        {tracking}
+
+        {category_items}
+
         /// The binds associated with the editor actions.
         #[derive(Clone, Copy, Debug, PartialEq, EnumIter, EnumSize)]
         pub enum Bind
-        {binds}
+        {{
+            {body}
+        }}
 
         impl Bind
         {{
+            /// All the `Bind`s, in declaration order.
+            pub const ALL: [Self; Self::SIZE] = [{all}];
+
             {key_func}
 
             {label_func}
+
+            {localized_label_func}
+
+            {description_func}
+
+            {settings_table_func}
+
+            {keycode_func}
+
+            {chord_func}
+
+            {reserved_keycodes}
+
+            /// The maximum amount of keys of a chord.
+            pub const MAX_CHORD_LEN: usize = {MAX_CHORD_LEN};
+
+            /// Returns the config file representation of `chord`, its keys separated by `>`.
+            #[inline]
+            #[must_use]
+            pub fn chord_to_config_string<K: std::fmt::Display>(chord: &[K]) -> String
+            {{
+                chord.iter().map(ToString::to_string).collect::<Vec<_>>().join(\" > \")
+            }}
+
+            /// Parses the config file representation of a chord, its keys separated by `>`,
+            /// parsing each key with `key`. Returns `None` if a key cannot be parsed or the
+            /// amount of keys is not between 1 and `MAX_CHORD_LEN`.
+            #[inline]
+            #[must_use]
+            pub fn parse_chord<K>(
+                value: &str,
+                key: impl FnMut(&str) -> Option<K>
+            ) -> Option<Vec<K>>
+            {{
+                let chord =
+                    value.split('>').map(str::trim).map(key).collect::<Option<Vec<_>>>()?;
+                (chord.len() <= Self::MAX_CHORD_LEN).then_some(chord)
+            }}
+
+            {from_key_func}
+
+            /// The amount of `Bind`s of the editor, which precede the ones provided by the
+            /// applications extending it.
+            pub const EDITOR_LEN: usize = Self::SIZE - {external_len};
+
+            /// The range of the indexes of the `Bind`s provided by the applications extending
+            /// the editor, which follow all the others.
+            pub const EXTERNAL: std::ops::Range<usize> = Self::EDITOR_LEN..Self::SIZE;
+
+            /// Whether this `Bind` is provided by an application extending the editor.
+            #[inline]
+            #[must_use]
+            pub const fn is_external(self) -> bool {{ (self as usize) >= Self::EDITOR_LEN }}
+
+            /// Returns the config file lines storing the keys of the `Bind`s, in the
+            /// `config_file_key = key` format, where `key` is the value returned by `key` for each
+            /// `Bind`. `Bind`s without key are skipped.
+            #[inline]
+            #[must_use]
+            pub fn to_config_string<K: std::fmt::Display>(
+                mut key: impl FnMut(Self) -> Option<K>
+            ) -> String
+            {{
+                let mut string = String::new();
+
+                for bind in Self::iter()
+                {{
+                    if let Some(key) = key(bind)
+                    {{
+                        string.push_str(&format!(\"{{}} = {{key}}\\n\", bind.config_file_key()));
+                    }}
+                }}
+
+                string
+            }}
+
+            /// Parses the config file lines in the `config_file_key = key` format contained in
+            /// `config`, returning the `Bind`s along with their keys parsed by `key`. Lines that
+            /// cannot be parsed are skipped.
+            #[inline]
+            #[must_use]
+            pub fn parse_config<K>(
+                config: &str,
+                mut key: impl FnMut(&str) -> Option<K>
+            ) -> Vec<(Self, K)>
+            {{
+                config
+                    .lines()
+                    .filter_map(|line| {{
+                        let (bind, value) = line.split_once('=')?;
+                        Some((Self::from_config_file_key(bind.trim())?, key(value.trim())?))
+                    }})
+                    .collect()
+            }}
+
+            /// Returns the pairs of `Bind`s associated with the same key in `binds`.
+            #[inline]
+            #[must_use]
+            pub fn conflicts(binds: &BindsKeyCodes) -> Vec<(Self, Self)>
+            {{
+                let mut conflicts = Vec::new();
+
+                for (i, a) in Self::iter().enumerate()
+                {{
+                    if let Some(key) = binds.get(a)
+                    {{
+                        conflicts.extend(
+                            Self::iter()
+                                .skip(i + 1)
+                                .filter(|b| binds.get(*b).is_some_and(|k| k == key))
+                                .map(|b| (a, b))
+                        );
+                    }}
+                }}
+
+                conflicts
+            }}
+
+            {tool_bind_func}
+
+            {subtool_bind_func}
+        }}
+
+        /// The error returned when a string is not the config file key of any [`Bind`].
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        pub struct BindFromStrError(pub String);
+
+        impl std::fmt::Display for BindFromStrError
+        {{
+            #[inline]
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+            {{
+                write!(f, \"{{:?}} is not a valid Bind\", self.0)
+            }}
+        }}
+
+        impl std::error::Error for BindFromStrError {{}}
+
+        impl std::str::FromStr for Bind
+        {{
+            type Err = BindFromStrError;
+
+            #[inline]
+            fn from_str(value: &str) -> Result<Self, Self::Err>
+            {{
+                Self::from_config_file_key(value).ok_or_else(|| \
+         BindFromStrError(value.to_string()))
+            }}
         }}"
     )
     .parse()
@@ -781,28 +7695,16 @@ fn tools_common(stream: TokenStream, id: &str) -> [String; 2]
     for item in stream
     {
         let ident = continue_if_no_match!(item, TokenTree::Ident(ident), ident).to_string();
-        let mut chars = ident.chars();
-
-        // Label.
-        let mut value = chars.next_value().to_string();
-
-        for ch in chars
-        {
-            if ch.is_ascii_uppercase()
-            {
-                value.push(' ');
-            }
-
-            value.push(ch);
-        }
 
         // Header.
-        value = value.to_ascii_uppercase();
-        header_func.push_str(&format!("Self::{ident} => \"{value} {id}\",\n"));
+        header_func.push_str(&format!(
+            "Self::{ident} => \"{} {id}\",\n",
+            label(&ident).to_ascii_uppercase()
+        ));
 
         // Icon paths.
-        value = value.to_ascii_lowercase().replace(' ', "_");
-        icon_file_name_func.push_str(&format!("Self::{ident} => \"{value}.png\",\n"));
+        icon_file_name_func
+            .push_str(&format!("Self::{ident} => \"{}.png\",\n", snake_case(&ident)));
     }
 
     for func in [&mut icon_file_name_func, &mut header_func]
@@ -816,6 +7718,7 @@ fn tools_common(stream: TokenStream, id: &str) -> [String; 2]
 //=======================================================================//
 
 /// Implements the vast majority of the methods of the `Tool` enum.
+/// Compilation fails if a variant has no associated `Bind` generated by [`bind_enum`].
 /// # Panics
 /// Panics if `input` does not belong to the `Tool` enum.
 #[proc_macro_derive(ToolEnum)]
@@ -823,7 +7726,7 @@ fn tools_common(stream: TokenStream, id: &str) -> [String; 2]
 pub fn declare_tool_enum(input: TokenStream) -> TokenStream
 {
     let mut iter = input.into_iter();
-    assert!(enum_ident(&mut iter).to_string() == "Tool");
+    assert!(enum_ident(&mut iter) == "Tool");
     let group = match_or_panic!(iter.next_value(), TokenTree::Group(g), g);
     let [header_func, icon_file_name_func] = tools_common(group.stream(), "TOOL");
 
@@ -844,25 +7747,12 @@ pub fn declare_tool_enum(input: TokenStream) -> TokenStream
     for item in group.stream()
     {
         let ident = continue_if_no_match!(item, TokenTree::Ident(ident), ident).to_string();
-        let mut chars = ident.chars();
 
         // Bind
         bind_func.push_str(&format!("Self::{ident} => Bind::{ident},\n"));
 
         // Label.
-        let mut value = chars.next_value().to_string();
-
-        for ch in chars
-        {
-            if ch.is_ascii_uppercase()
-            {
-                value.push(' ');
-            }
-
-            value.push(ch);
-        }
-
-        label_func.push_str(&format!("Self::{ident} => \"{value}\",\n"));
+        label_func.push_str(&format!("Self::{ident} => \"{}\",\n", label(&ident)));
     }
 
     for func in [&mut label_func, &mut bind_func]
@@ -870,8 +7760,24 @@ pub fn declare_tool_enum(input: TokenStream) -> TokenStream
         func.push_str("}\n}");
     }
 
+    let mut binds_check = "const _: () = {\n".to_string();
+
+    for variant in enum_variants(TokenTree::Group(group))
+    {
+        let ident = variant.ident.to_string();
+        binds_check.push_str(&format!(
+            "{}assert!(Bind::tool_bind_exists(\"{ident}\"), \"The Tool variant {ident} has no \
+             associated Bind, the Tool enum read by bind_enum is outdated.\");\n",
+            variant_cfg(&variant)
+        ));
+    }
+
+    binds_check.push_str("};");
+
     format!(
         "
+        {binds_check}
+
         impl ToolInterface for Tool
         {{
             {label_func}
@@ -911,15 +7817,36 @@ pub fn declare_tool_enum(input: TokenStream) -> TokenStream
 //=======================================================================//
 
 /// Implements the vast majority of the methods of the `SubTool` enum.
+/// The generated `rebindable_bind` method returns the `Bind` generated by [`bind_enum`] for the
+/// subtool, if any.
+/// The binds of the subtools are read from the `subtools binds` subdirectory of the docs
+/// directory, which can be specified as in [`generate_manual`] with `#[subtool_docs("path")]` on
+/// the enum, `docs` by default. If the `platform_keys` option of [`generate_manual`] is set to
+/// true, such as in `#[subtool_docs(platform_keys = true)]`, the binds mentioning the Ctrl key
+/// mention the Cmd key instead when compiling for macOS.
 /// # Panics
 /// Panics if `input` does not belong to the `SubTool` enum.
-#[proc_macro_derive(SubToolEnum)]
+#[proc_macro_derive(SubToolEnum, attributes(subtool_docs))]
 #[allow(clippy::too_many_lines)]
 #[must_use]
 pub fn subtool_enum(input: TokenStream) -> TokenStream
 {
+    let (docs, options) = manual_arguments(
+        find_attribute(&Enum::new(input.clone()).attributes, "subtool_docs").unwrap_or_default()
+    );
+    let mut platform = false;
+
+    for (key, value) in options
+    {
+        match key.as_str()
+        {
+            "platform_keys" => platform = bool_option(&key, &value),
+            key => panic!("Unknown subtool_docs option {key}.")
+        };
+    }
+
     let mut iter = input.into_iter();
-    assert!(enum_ident(&mut iter).to_string() == "SubTool");
+    assert!(enum_ident(&mut iter) == "SubTool");
     let group = match_or_panic!(iter.next_value(), TokenTree::Group(g), g);
     let [header_func, icon_file_name_func] = tools_common(group.stream(), "SUBTOOL");
 
@@ -947,17 +7874,26 @@ pub fn subtool_enum(input: TokenStream) -> TokenStream
             {\n"
     .to_string();
 
+    let mut rebindable_bind_func = "
+        /// Returns the `Bind` associated with this `SubTool`, if the subtools were made
+        /// rebindable in `bind_enum!`.
+        #[inline]
+        #[must_use]
+        const fn rebindable_bind(self) -> Option<Bind>
+        {
+            match self
+            {\n"
+    .to_string();
+
     let mut tool = String::new();
     let mut label = String::new();
     let mut bind = String::new();
-    let mut subtool_binds_path = std::env::current_dir().unwrap();
-    subtool_binds_path.push("docs");
-    subtool_binds_path.push("subtools binds");
+    let mut subtool_binds_path = docs.join("subtools binds");
 
     for item in group.stream()
     {
         let ident = continue_if_no_match!(item, TokenTree::Ident(ident), ident).to_string();
-        let mut chars = ident.chars();
+        let mut chars = unraw(&ident).chars();
         let first = chars.next_value();
 
         for s in [&mut tool, &mut label, &mut bind]
@@ -999,12 +7935,33 @@ pub fn subtool_enum(input: TokenStream) -> TokenStream
 
         label_func.push_str(&format!("Self::{ident} => \"{label}\",\n"));
         tool_func.push_str(&format!("Self::{ident} => Tool::{tool},\n"));
-        bind_func.push_str(&format!("Self::{ident} => include_str!({:?}),\n", subtool_binds_path));
+        rebindable_bind_func
+            .push_str(&format!("Self::{ident} => Bind::subtool_bind(\"{ident}\"),\n"));
+        let binds = std::fs::read_to_string(&subtool_binds_path).unwrap_or_default();
+        let include = format!("include_str!({:?})", subtool_binds_path);
+
+        if platform && binds.contains("Ctrl")
+        {
+            bind_func.push_str(&format!(
+                "Self::{ident} => if cfg!(target_os = \"macos\") {{ {:?} }} else {{ {include} \
+                 }},\n",
+                binds.replace("Ctrl", "Cmd")
+            ));
+        }
+        else
+        {
+            bind_func.push_str(&format!("Self::{ident} => {include},\n"));
+        }
 
         subtool_binds_path.pop();
     }
 
-    for func in [&mut label_func, &mut tool_func, &mut bind_func]
+    for func in [
+        &mut label_func,
+        &mut tool_func,
+        &mut bind_func,
+        &mut rebindable_bind_func
+    ]
     {
         func.push_str("}\n}");
     }
@@ -1043,6 +8000,8 @@ pub fn subtool_enum(input: TokenStream) -> TokenStream
             {tool_func}
 
             {bind_func}
+
+            {rebindable_bind_func}
         }}
         "
     )
@@ -1053,15 +8012,15 @@ pub fn subtool_enum(input: TokenStream) -> TokenStream
 //=======================================================================//
 
 /// Generates the function calls to store the embedded assets from the file names in the
-/// `src/embedded_assets/` folder.
+/// `src/embedded_assets/` folder of the crate.
 /// # Panics
 /// Panics if the required folder cannot be found.
 #[allow(clippy::missing_panics_doc)]
 #[proc_macro]
 pub fn embedded_assets(_: TokenStream) -> TokenStream
 {
-    let mut path = std::env::current_dir().unwrap();
-    path.push("src/embedded_assets/");
+    let path = std::path::Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap())
+        .join("src/embedded_assets/");
 
     // Get all the files.
     let directory = std::fs::read_dir(path).unwrap();
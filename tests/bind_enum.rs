@@ -0,0 +1,153 @@
+mod config
+{
+    pub mod controls
+    {
+        use hill_vacuum_proc_macros::{bind_enum, EnumIter, EnumSize};
+
+        /// A mock of the keyboard keys.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum KeyCode
+        {
+            ArrowLeft,
+            ArrowRight,
+            KeyG,
+            KeyT
+        }
+
+        pub struct BindsKeyCodes(pub [Option<u32>; Bind::SIZE]);
+
+        impl BindsKeyCodes
+        {
+            pub const fn get(&self, bind: Bind) -> Option<u32> { self.0[bind as usize] }
+        }
+
+        #[derive(Clone, Copy)]
+        pub enum Language
+        {
+            En,
+            It
+        }
+
+        bind_enum!(
+            view: Left = ArrowLeft "Moves the view to the left.",
+            #[cfg(any())]
+            Up,
+            edit: #[cfg(test)]
+            Right,
+            GoTo = KeyG > KeyT "Goes to the grid.",
+            tools = [
+                /// Draws a square.
+                Square,
+                #[cfg(any())]
+                Triangle,
+                Circle
+            ],
+            subtools = [
+                ClipSide,
+                #[cfg(any())]
+                ClipHidden
+            ],
+            labels = [It = [Left = "Sinistra", Square = "Quadrato"]],
+            reserved = [ArrowRight],
+            external = [
+                Export = KeyT,
+                /// Runs the plugin.
+                Plugin
+            ]
+        );
+
+        // `config_file_key` is only visible to the controls module.
+        #[test]
+        fn config_file_keys()
+        {
+            for bind in Bind::ALL
+            {
+                assert_eq!(Bind::from_config_file_key(bind.config_file_key()), Some(bind));
+                assert_eq!(bind.config_file_key().parse::<Bind>(), Ok(bind));
+            }
+
+            assert_eq!(Bind::SubToolClipSide.config_file_key(), "subtool_clip_side");
+            assert_eq!(Bind::from_config_file_key("triangle"), None);
+            assert_eq!(Bind::subtool_bind("ClipHidden"), None);
+        }
+    }
+}
+
+//=======================================================================//
+
+use config::controls::{Bind, BindCategory, BindsKeyCodes, KeyCode, Language};
+
+//=======================================================================//
+
+#[test]
+fn variants()
+{
+    assert_eq!(Bind::SIZE, 8);
+    assert_eq!(Bind::ALL, [
+        Bind::Left,
+        Bind::Right,
+        Bind::GoTo,
+        Bind::Square,
+        Bind::Circle,
+        Bind::SubToolClipSide,
+        Bind::Export,
+        Bind::Plugin
+    ]);
+    assert_eq!(Bind::ALL.to_vec(), Bind::iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn categories()
+{
+    assert_eq!(Bind::Left.category(), BindCategory::View);
+    assert_eq!(Bind::GoTo.category(), BindCategory::Edit);
+    assert_eq!(Bind::Circle.category(), BindCategory::Tools);
+    assert_eq!(Bind::SubToolClipSide.category(), BindCategory::Subtools);
+    assert_eq!(Bind::Plugin.category(), BindCategory::External);
+}
+
+#[test]
+fn labels_and_descriptions()
+{
+    assert_eq!(Bind::Left.label(Language::It), "Sinistra");
+    assert_eq!(Bind::Left.label(Language::En), "Left");
+    assert_eq!(Bind::Circle.label(Language::It), "Circle");
+    assert_eq!(Bind::GoTo.description(), "Goes to the grid.");
+    assert_eq!(Bind::Square.description(), "Draws a square.");
+    assert_eq!(Bind::Plugin.description(), "Runs the plugin.");
+}
+
+#[test]
+fn default_keys()
+{
+    assert_eq!(Bind::Left.default_keycode(), Some(KeyCode::ArrowLeft));
+    assert_eq!(Bind::Right.default_keycode(), None);
+    assert_eq!(Bind::GoTo.default_keycode(), None);
+    assert_eq!(Bind::GoTo.default_chord(), Some([KeyCode::KeyG, KeyCode::KeyT].as_slice()));
+    assert_eq!(Bind::RESERVED_KEYCODES, [KeyCode::ArrowRight]);
+    assert_eq!(Bind::chord_to_config_string(&["g", "t"]), "g > t");
+    assert_eq!(Bind::parse_chord("g >t", |key| key.chars().next()), Some(vec!['g', 't']));
+    assert_eq!(Bind::parse_chord("g > > t", |key| key.chars().next()), None);
+}
+
+#[test]
+fn external()
+{
+    assert_eq!(Bind::EDITOR_LEN, 6);
+    assert_eq!(Bind::EXTERNAL, 6..8);
+    assert_eq!(Bind::Export as usize, Bind::EXTERNAL.start);
+    assert!(Bind::Plugin.is_external() && !Bind::SubToolClipSide.is_external());
+    assert_eq!(Bind::Export.default_keycode(), Some(KeyCode::KeyT));
+}
+
+#[test]
+fn conflicts()
+{
+    let mut keys = [None; Bind::SIZE];
+    keys[Bind::Left as usize] = Some(1);
+    keys[Bind::Square as usize] = Some(1);
+    keys[Bind::Right as usize] = Some(2);
+
+    assert_eq!(Bind::conflicts(&BindsKeyCodes(keys)), [(Bind::Left, Bind::Square)]);
+    assert!(Bind::conflicts(&BindsKeyCodes([None; Bind::SIZE])).is_empty());
+}
@@ -0,0 +1,151 @@
+mod map
+{
+    pub mod drawer
+    {
+        use hill_vacuum_proc_macros::{color_category, color_enum, EnumIter, EnumSize};
+
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, EnumSize)]
+        pub enum Color
+        {
+            Clear,
+            Extensions,
+            Backdrop,
+            Reference,
+            Soft,
+            Lines,
+            Origin,
+            Brush,
+            Selected,
+            Highlighted,
+            Vertex,
+            Cursor,
+            Tooltip,
+            Hover
+        }
+
+        color_category!(pub);
+
+        impl Color
+        {
+            color_enum!(
+                max_height = 1000,
+                clear: Clear = #000000,
+                extensions: Extensions = #ff8800,
+                background: Backdrop = #101010,
+                Reference = -10 #202020,
+                grid: Soft = #404040,
+                Lines = 200.5 #808080,
+                Origin = "#1eee00" "#0e0e0e",
+                entities: Brush = #ffffff80,
+                Selected = 100 #ff0000,
+                Highlighted = #ffff00,
+                Vertex = #0000ff,
+                ui(step = 0.5): Vertex,
+                Cursor = 299 #00ff00,
+                Tooltip = #ffffff #000000,
+                alias Hover = Highlighted
+            );
+        }
+    }
+}
+
+//=======================================================================//
+
+use map::drawer::{Color, ColorCategory};
+
+//=======================================================================//
+
+#[test]
+fn sections()
+{
+    assert_eq!(Color::grid_colors().collect::<Vec<_>>(), [
+        Color::Soft,
+        Color::Lines,
+        Color::Origin
+    ]);
+    assert_eq!(Color::entity_colors().collect::<Vec<_>>(), [
+        Color::Brush,
+        Color::Selected,
+        Color::Highlighted,
+        Color::Vertex
+    ]);
+    assert_eq!(Color::ui_colors().collect::<Vec<_>>(), [
+        Color::Vertex,
+        Color::Cursor,
+        Color::Tooltip
+    ]);
+    assert_eq!(Color::Lines.category(), ColorCategory::Grid);
+    assert_eq!(Color::Reference.category(), ColorCategory::Background);
+    assert!(Color::Vertex.is_entity() && Color::Vertex.is_ui());
+}
+
+#[test]
+fn pinned_heights()
+{
+    const SELECTED: f32 = Color::Selected.entity_height();
+
+    assert_eq!(SELECTED, 100.0);
+    assert_eq!(Color::Lines.line_height(), 200.5);
+    assert_eq!(Color::Cursor.square_hgl_height(), 299.0);
+    assert_eq!(Color::Reference.background_height(), -10.0);
+    assert_eq!(Color::Origin.line_height(), Color::Soft.line_height() + 1.0);
+    assert_eq!(Color::Tooltip.square_hgl_height(), Color::Vertex.square_hgl_height() + 0.5);
+}
+
+#[test]
+fn height_table()
+{
+    assert_eq!(Color::HEIGHT_TABLE.len(), Color::SIZE - 1);
+
+    for (color, height, line_height) in Color::HEIGHT_TABLE
+    {
+        assert_eq!(*line_height, color.is_entity().then(|| color.line_height()));
+
+        if color.is_entity()
+        {
+            assert_eq!(*height, color.entity_height());
+        }
+    }
+
+    let mut heights = Color::HEIGHT_TABLE
+        .iter()
+        .filter(|(color, ..)| *color != Color::Hover)
+        .flat_map(|(_, height, line_height)| [Some(*height), *line_height])
+        .flatten()
+        .collect::<Vec<_>>();
+    heights.sort_by(f32::total_cmp);
+    assert!(heights.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn aliases()
+{
+    assert_eq!(Color::Hover.entity_height(), Color::Highlighted.entity_height());
+    assert_eq!(Color::Hover.config_file_key(), "highlighted");
+    assert_eq!(Color::from_config_file_key("hover"), None);
+    assert!(!Color::ui_colors().any(|color| color == Color::Hover));
+}
+
+#[test]
+fn default_colors()
+{
+    assert_eq!(Color::Extensions.default_color(), [255, 136, 0, 255]);
+    assert_eq!(Color::Brush.default_color(), [255, 255, 255, 128]);
+    assert_eq!(Color::Origin.default_light_color(), [14, 14, 14, 255]);
+    assert_eq!(Color::Cursor.default_light_color(), Color::Cursor.default_color());
+}
+
+#[test]
+fn config_entries()
+{
+    let line = Color::Extensions.to_config_string([255, 136, 0, 255]);
+    assert_eq!(line, "extensions = #ff8800ff");
+    assert_eq!(Color::parse_config_entry(&line), Some((Color::Extensions, [255, 136, 0, 255])));
+    assert_eq!(
+        Color::parse_config_entry(" tooltip=#00ff00 "),
+        Some((Color::Tooltip, [0, 255, 0, 255]))
+    );
+    assert_eq!(Color::parse_config_entry("tooltip = #00ffzz"), None);
+    assert_eq!(Color::parse_config_entry("tooltip = #+f+f+f"), None);
+    assert_eq!(Color::parse_config_entry("unknown = #00ff00"), None);
+}
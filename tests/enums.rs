@@ -0,0 +1,110 @@
+use hill_vacuum_proc_macros::{
+    EnumConfigKey,
+    EnumCycle,
+    EnumDisplay,
+    EnumFromStr,
+    EnumFromUsize,
+    EnumIter,
+    EnumLabel,
+    EnumMap,
+    EnumSize,
+    EnumTable,
+    EnumVariantNames,
+    EnumVariants
+};
+
+//=======================================================================//
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    EnumSize,
+    EnumIter,
+    EnumFromUsize,
+    EnumVariants,
+    EnumCycle,
+    EnumVariantNames,
+    EnumFromStr,
+    EnumDisplay,
+    EnumMap,
+    EnumConfigKey,
+    EnumLabel,
+)]
+enum Tool
+{
+    Square,
+    #[cfg(any())]
+    Triangle,
+    #[cfg(test)]
+    Circle,
+    FreeDraw,
+    #[cfg(any())]
+    Zoom
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumTable)]
+enum Height
+{
+    #[value(1.5)]
+    Low,
+    #[cfg(any())]
+    #[value(0.0)]
+    Gone,
+    #[value(-2e3)]
+    High
+}
+
+//=======================================================================//
+
+#[test]
+fn cfg_size_and_iter()
+{
+    assert_eq!(Tool::SIZE, 3);
+    assert_eq!(Tool::iter().collect::<Vec<_>>(), [Tool::Square, Tool::Circle, Tool::FreeDraw]);
+    assert_eq!(Tool::VARIANTS, [Tool::Square, Tool::Circle, Tool::FreeDraw]);
+    assert_eq!(Tool::NAMES, ["Square", "Circle", "FreeDraw"]);
+}
+
+#[test]
+fn cfg_from_usize()
+{
+    assert_eq!(Tool::try_from(1usize), Ok(Tool::Circle));
+    assert_eq!(Tool::try_from(2usize), Ok(Tool::FreeDraw));
+    assert_eq!(Tool::try_from(3usize), Err(ToolFromUsizeError(3)));
+}
+
+#[test]
+fn cfg_cycle()
+{
+    assert_eq!(Tool::Square.next(), Tool::Circle);
+    assert_eq!(Tool::FreeDraw.next(), Tool::Square);
+    assert_eq!(Tool::Square.prev(), Tool::FreeDraw);
+}
+
+#[test]
+fn cfg_strings()
+{
+    assert_eq!("Circle".parse::<Tool>(), Ok(Tool::Circle));
+    assert!("Triangle".parse::<Tool>().is_err());
+    assert_eq!(Tool::FreeDraw.to_string(), "Free Draw");
+    assert_eq!(Tool::FreeDraw.label(), "Free Draw");
+    assert_eq!(Tool::FreeDraw.config_file_key(), "free_draw");
+    assert_eq!(Tool::from_config_file_key("zoom"), None);
+}
+
+#[test]
+fn cfg_map()
+{
+    let mut map = ToolMap::from_fn(|tool| tool as usize);
+    map[Tool::FreeDraw] += 1;
+    assert_eq!(map.iter().map(|(_, value)| *value).collect::<Vec<_>>(), [0, 1, 3]);
+}
+
+#[test]
+fn cfg_table()
+{
+    assert_eq!(Height::Low.value(), 1.5);
+    assert_eq!(Height::High.value(), -2000.0);
+}
@@ -0,0 +1,59 @@
+use hill_vacuum_proc_macros::{named_enum, str_array, str_array_from_file};
+
+//=======================================================================//
+
+str_array!(PLAIN, 3);
+str_array!(AFFIXES, 2, i_, _px);
+str_array!(PADDED, 120, frame_, pad = 3);
+str_array!(RANGE, 8..11, slot_);
+str_array!(INCLUSIVE, 1..=3, n, pad = 2);
+str_array!(TEMPLATE, 9..11, "{i}-{i}", pad = 3);
+str_array!(STEP, 1..=7, step = 3);
+str_array!(GRID, 1..3, g, _x, columns = 2, pad = 2);
+str_array!(BYTES, 2, k_, bytes = true);
+str_array!(ALPHA, 25..28, alpha = true);
+str_array!(HEX, 9..12, id_, hex = true, pad = 2);
+str_array!(STATIC, 2, s_, storage = static);
+str_array!(name = NAMED, count = 2, prefix = i_, suffix = _px);
+
+str_array_from_file!(LINES, "tests/data/categories.txt");
+str_array_from_file!(TRIMMED, "tests/data/categories.txt", trim = true);
+str_array_from_file!(CATEGORIES, "tests/data/categories.txt", trim = true, skip_empty = true);
+
+named_enum!(TextureCategory, ["wood", "stone", "dark metal", "sky-box_2"]);
+
+//=======================================================================//
+
+#[test]
+fn str_array()
+{
+    assert_eq!(PLAIN, ["0", "1", "2"]);
+    assert_eq!(AFFIXES, ["i_0_px", "i_1_px"]);
+    assert_eq!((PADDED[0], PADDED[119]), ("frame_000", "frame_119"));
+    assert_eq!(RANGE, ["slot_8", "slot_9", "slot_10"]);
+    assert_eq!(INCLUSIVE, ["n01", "n02", "n03"]);
+    assert_eq!(TEMPLATE, ["009-009", "010-010"]);
+    assert_eq!(STEP, ["1", "4", "7"]);
+    assert_eq!(GRID, [["g01_00_x", "g01_01_x"], ["g02_00_x", "g02_01_x"]]);
+    assert_eq!(BYTES, [b"k_0".as_slice(), b"k_1"]);
+    assert_eq!(ALPHA, ["z", "aa", "ab"]);
+    assert_eq!(HEX, ["id_09", "id_0a", "id_0b"]);
+    assert_eq!(STATIC, ["s_0", "s_1"]);
+    assert_eq!(NAMED, ["i_0_px", "i_1_px"]);
+}
+
+#[test]
+fn str_array_from_file()
+{
+    assert_eq!(LINES, ["wood", "  stone ", "", "\"quoted\""]);
+    assert_eq!(TRIMMED, ["wood", "stone", "", "\"quoted\""]);
+    assert_eq!(CATEGORIES, ["wood", "stone", "\"quoted\""]);
+}
+
+#[test]
+fn named_enum()
+{
+    assert_eq!(TEXTURE_CATEGORY_STRS, ["wood", "stone", "dark metal", "sky-box_2"]);
+    assert_eq!(TextureCategory::DarkMetal.as_str(), "dark metal");
+    assert_eq!(TextureCategory::SkyBox2 as usize, 3);
+}
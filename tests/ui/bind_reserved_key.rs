@@ -0,0 +1,11 @@
+mod config
+{
+    pub mod controls
+    {
+        use hill_vacuum_proc_macros::bind_enum;
+
+        bind_enum!(Left = ArrowLeft, Right = ArrowRight, tools = [], reserved = [ArrowRight]);
+    }
+}
+
+fn main() {}
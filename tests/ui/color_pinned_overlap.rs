@@ -0,0 +1,32 @@
+mod map
+{
+    pub mod drawer
+    {
+        use hill_vacuum_proc_macros::{color_category, color_enum, EnumIter, EnumSize};
+
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, EnumSize)]
+        pub enum Color
+        {
+            Clear,
+            Extensions,
+            Lines,
+            Brush,
+            Selected
+        }
+
+        color_category!(pub);
+
+        impl Color
+        {
+            color_enum!(
+                clear: Clear = #000000,
+                extensions: Extensions = #ff8800,
+                grid: Lines = #808080,
+                entities: Brush = #ffffff,
+                Selected = 1.5 #ff0000
+            );
+        }
+    }
+}
+
+fn main() {}
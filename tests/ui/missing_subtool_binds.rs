@@ -0,0 +1,7 @@
+use hill_vacuum_proc_macros::generate_manual_text;
+
+// The docs are resolved against the manifest of the crate built by trybuild, in
+// `target/tests/trybuild/hill_vacuum_proc_macros`.
+generate_manual_text!("../../../../tests/ui/missing_binds");
+
+fn main() {}
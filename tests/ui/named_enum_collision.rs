@@ -0,0 +1,5 @@
+use hill_vacuum_proc_macros::named_enum;
+
+named_enum!(TextureCategory, ["dark metal", "dark_metal"]);
+
+fn main() {}
@@ -0,0 +1,5 @@
+use hill_vacuum_proc_macros::str_array;
+
+str_array!(IDS, 8..8, slot_);
+
+fn main() {}
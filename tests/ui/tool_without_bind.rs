@@ -0,0 +1,48 @@
+mod config
+{
+    pub mod controls
+    {
+        use hill_vacuum_proc_macros::{bind_enum, EnumIter, EnumSize};
+
+        pub struct BindsKeyCodes;
+
+        impl BindsKeyCodes
+        {
+            pub const fn get(&self, _: Bind) -> Option<u32> { None }
+        }
+
+        bind_enum!(Left, tools = [Square]);
+    }
+}
+
+use config::controls::{Bind, BindsKeyCodes};
+use hill_vacuum_proc_macros::ToolEnum;
+
+trait ToolInterface
+{
+    fn label(self) -> &'static str;
+    fn header(self) -> &'static str;
+    fn icon_file_name(self) -> &'static str;
+    fn tooltip_label(self, binds: &BindsKeyCodes) -> String;
+    fn change_conditions_met(self, change_conditions: &ChangeConditions) -> bool;
+    fn subtool(self) -> bool;
+    fn index(self) -> usize;
+}
+
+struct ChangeConditions;
+
+#[derive(ToolEnum, Clone, Copy)]
+enum Tool
+{
+    Square,
+    Circle
+}
+
+impl Tool
+{
+    const fn conditions_met(self, _: &ChangeConditions) -> bool { true }
+
+    fn keycode_str(self, _: &BindsKeyCodes) -> &'static str { "" }
+}
+
+fn main() {}